@@ -0,0 +1,26 @@
+//! Compares `Tork::detect` with `Tork::detect_cow` on input without PII
+//!
+//! Run with `cargo bench --bench clean`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use tork_governance::Tork;
+
+fn clean_input() -> String {
+    let line = "The quarterly report is attached; please review the summary \
+                and send any comments before the meeting.\n";
+    line.repeat(2_000)
+}
+
+fn bench_clean(c: &mut Criterion) {
+    let input = clean_input();
+    let tork = Tork::new();
+
+    let mut group = c.benchmark_group("clean_input");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("detect", |b| b.iter(|| tork.detect(black_box(&input))));
+    group.bench_function("detect_cow", |b| b.iter(|| tork.detect_cow(black_box(&input))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_clean);
+criterion_main!(benches);
@@ -0,0 +1,61 @@
+//! Criterion benchmarks for detection, governance and scanning
+//!
+//! Run with `cargo bench --bench detection`. Each function is measured on
+//! short, medium and long inputs, both clean and full of PII.
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use tork_governance::{builtin_patterns, detect_pii, Tork};
+
+const CLEAN_LINE: &str = "The quarterly report is attached; please review the summary \
+                          and send any comments before the meeting.\n";
+const DIRTY_LINE: &str = "Contact john@example.com or 555-123-4567, SSN 123-45-6789, \
+                          card 4111-1111-1111-1111, and nothing else of note here.\n";
+
+/// `(label, input)` for every size and variant
+fn inputs() -> Vec<(String, String)> {
+    let sizes = [("short", 1), ("medium", 20), ("long", 2_000)];
+    let mut inputs = Vec::new();
+    for (size, lines) in sizes {
+        inputs.push((format!("{}/clean", size), CLEAN_LINE.repeat(lines)));
+        inputs.push((format!("{}/dirty", size), DIRTY_LINE.repeat(lines)));
+    }
+    inputs
+}
+
+fn bench_detection(c: &mut Criterion) {
+    // Compile the shared patterns before measuring anything
+    builtin_patterns();
+    let inputs = inputs();
+
+    let mut group = c.benchmark_group("detect_pii");
+    for (label, input) in &inputs {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(label), input, |b, input| {
+            b.iter(|| detect_pii(black_box(input)))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("govern");
+    let tork = Tork::new();
+    for (label, input) in &inputs {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(label), input, |b, input| {
+            b.iter(|| tork.govern(black_box(input)))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("scan");
+    let tork = Tork::new();
+    for (label, input) in &inputs {
+        group.throughput(Throughput::Bytes(input.len() as u64));
+        group.bench_with_input(BenchmarkId::from_parameter(label), input, |b, input| {
+            b.iter(|| tork.scan(black_box(input)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_detection);
+criterion_main!(benches);
@@ -0,0 +1,26 @@
+//! Compares `Tork::scan` with `detect_pii` on a large input
+//!
+//! Run with `cargo bench --bench scan`.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, Throughput};
+use tork_governance::{detect_pii, Tork};
+
+fn large_input() -> String {
+    let line = "Contact john@example.com or 555-123-4567, SSN 123-45-6789, \
+                card 4111-1111-1111-1111, and nothing else of note here.\n";
+    line.repeat(2_000)
+}
+
+fn bench_scan(c: &mut Criterion) {
+    let input = large_input();
+    let tork = Tork::new();
+
+    let mut group = c.benchmark_group("scan_vs_detect");
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("detect_pii", |b| b.iter(|| detect_pii(black_box(&input))));
+    group.bench_function("scan", |b| b.iter(|| tork.scan(black_box(&input))));
+    group.finish();
+}
+
+criterion_group!(benches, bench_scan);
+criterion_main!(benches);
@@ -0,0 +1,94 @@
+//! `tork` command-line scanner
+//!
+//! ```text
+//! tork scan notes.txt
+//! cat app.log | tork scan - --format json
+//! tork scan dump.csv --deny-exit-code 1
+//! ```
+
+use clap::{Parser, Subcommand, ValueEnum};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use tork_governance::{GovernanceAction, Tork};
+
+#[derive(Parser)]
+#[command(name = "tork", version, about = "Scan text for PII and redact it")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Govern a file, or stdin when the path is `-`
+    Scan {
+        /// File to scan, or `-` for stdin
+        path: PathBuf,
+        /// Output the redacted text or a JSON report with matches and receipt
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Exit with this code when PII is found or the input is denied
+        #[arg(long, value_name = "CODE")]
+        deny_exit_code: Option<u8>,
+        /// Policy file (TOML, or JSON for `.json`) instead of the default policy
+        #[arg(long, value_name = "FILE")]
+        policy: Option<PathBuf>,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Json,
+}
+
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(code) => code,
+        Err(message) => {
+            eprintln!("tork: {}", message);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<ExitCode, String> {
+    let Command::Scan {
+        path,
+        format,
+        deny_exit_code,
+        policy,
+    } = cli.command;
+
+    let tork = match policy {
+        Some(policy) => Tork::from_policy_file(&policy).map_err(|e| e.to_string())?,
+        None => Tork::new(),
+    };
+    let input = read_input(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let result = tork.govern(&input);
+
+    match format {
+        Format::Text => print!("{}", result.output),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&result).map_err(|e| e.to_string())?
+        ),
+    }
+
+    let flagged = result.pii.has_pii || result.action == GovernanceAction::Deny;
+    Ok(match deny_exit_code {
+        Some(code) if flagged => ExitCode::from(code),
+        _ => ExitCode::SUCCESS,
+    })
+}
+
+fn read_input(path: &Path) -> std::io::Result<String> {
+    if path.as_os_str() == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        Ok(input)
+    } else {
+        std::fs::read_to_string(path)
+    }
+}
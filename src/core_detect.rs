@@ -0,0 +1,628 @@
+//! Built-in PII detection that runs without `std`
+//!
+//! Needs only `core` and `alloc`, matching with `regex-automata` instead of
+//! `regex`, so it builds for embedded and edge targets with
+//! `default-features = false`:
+//!
+//! ```rust
+//! use tork_governance::core_detect::CoreDetector;
+//! use tork_governance::PIIType;
+//!
+//! let detector = CoreDetector::new();
+//! let result = detector.detect_pii("SSN: 123-45-6789");
+//!
+//! assert_eq!(result.redacted_text, "SSN: [SSN_REDACTED]");
+//! assert_eq!(result.matches[0].pii_type, PIIType::Ssn);
+//! ```
+//!
+//! [`CoreDetector`] runs the same built-in patterns, validators and overlap
+//! rules as [`detect_pii`](crate::detect_pii) under the default config.
+//! Names, postal codes and Bitcoin address checksums are left to the full
+//! API. There is no clock, so
+//! ISO, dotted and written-out birth dates are only reported after a birth
+//! keyword such as "born" or "DOB" unless today's date is supplied with
+//! [`CoreDetector::with_today`].
+
+use crate::PIIType;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use regex_automata::meta::Regex;
+use regex_automata::Input;
+use serde::{Deserialize, Serialize};
+
+/// Default for [`TorkConfig::min_confidence`](crate::TorkConfig::min_confidence)
+pub const DEFAULT_MIN_CONFIDENCE: f32 = 0.5;
+
+/// Default for [`TorkConfig::numeric_precedence`](crate::TorkConfig::numeric_precedence)
+pub(crate) const DEFAULT_NUMERIC_PRECEDENCE: [PIIType; 4] =
+    [PIIType::Ssn, PIIType::CreditCard, PIIType::Phone, PIIType::BankAccount];
+
+/// A built-in detector before compilation
+pub(crate) struct BuiltinPattern {
+    pub(crate) pii_type: PIIType,
+    /// Bordered by `\b` on both ends, except for `Address`
+    pub(crate) pattern: &'static str,
+    pub(crate) confidence: f32,
+    pub(crate) validator: Option<fn(&str) -> bool>,
+    /// Checked after `validator`, against the whole text and today's date
+    pub(crate) context: Option<ContextCheck>,
+}
+
+/// Whether a match over `start..end` of `text` stands, given today's date
+/// as `(year, month, day)` if known
+pub(crate) type ContextCheck = fn(text: &str, start: usize, end: usize, today: Option<(i32, u32, u32)>) -> bool;
+
+/// Built-in detectors in priority order; an earlier one claims a span that
+/// overlaps a later one during redaction
+pub(crate) const BUILTIN_PATTERNS: &[BuiltinPattern] = &[
+    BuiltinPattern {
+        pii_type: PIIType::Ssn,
+        pattern: r"\b\d{3}-\d{2}-\d{4}\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::Ein,
+        // Word boundaries make each tax ID layout match only whole
+        // tokens, so `12-3456789` and `123-45-6789` never overlap
+        pattern: r"\b\d{2}-\d{7}\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::CreditCard,
+        // 4-4-4-4 digits with any mix of single space or hyphen
+        // separators; Luhn rejects most arbitrary 16-digit runs
+        pattern: r"\b(?P<iin>\d{4})[- ]?(?P<middle>\d{4}[- ]?\d{4})[- ]?(?P<last4>\d{4})\b",
+        confidence: 1.0,
+        validator: Some(luhn_valid),
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::CreditCard,
+        // American Express, grouped 4-6-5
+        pattern: r"\b(?P<iin>3[47]\d{2})[- ]?(?P<middle>\d{6}[- ]?\d)(?P<last4>\d{4})\b",
+        confidence: 1.0,
+        validator: Some(card_number_valid),
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::CreditCard,
+        // Diners Club, grouped 4-6-4
+        pattern: r"\b(?P<iin>3(?:0[0-5]|[689]\d)\d)[- ]?(?P<middle>\d{6})[- ]?(?P<last4>\d{4})\b",
+        confidence: 1.0,
+        validator: Some(card_number_valid),
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::CreditCard,
+        // Ungrouped 13-19 digits, kept only when the length fits a known
+        // issuer so that long account numbers aren't claimed as cards
+        pattern: r"\b(?P<iin>\d{4})(?P<middle>\d{5,11})(?P<last4>\d{4})\b",
+        confidence: 1.0,
+        validator: Some(card_number_valid),
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::Email,
+        pattern: r"\b(?P<local>[A-Za-z0-9._%+-]+)@(?P<domain>[A-Za-z0-9.-]+\.[A-Za-z]{2,})\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::Phone,
+        pattern: r"\b(?:\+?(?P<country>1)[-.\s]?)?\(?(?P<area>\d{3})\)?[-.\s]?(?P<exchange>\d{3})[-.\s]?(?P<line>\d{4})\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::Address,
+        // A numbered street of up to four words with an optional unit
+        // designator, or a PO box
+        pattern: r"(?i)\b\d{1,5}\s+\w+(?:\s+\w+){0,3}\s+(?:Street|St|Avenue|Ave|Road|Rd|Boulevard|Blvd|Drive|Dr|Lane|Ln|Court|Ct|Way|Place|Pl)\b(?:\.?,?\s+(?:Apt|Apartment|Suite|Ste|Unit)\.?\s*#?\s*\w+\b|\.?,?\s*#\s*\w+\b)?|\bP\.?\s*O\.?\s*Box\s*\d+\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::IpAddress,
+        pattern: r"\b(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::DateOfBirth,
+        pattern: r"\b(?:0[1-9]|1[0-2])/(?:0[1-9]|[12]\d|3[01])/(?:19|20)\d{2}\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::DateOfBirth,
+        // ISO `YYYY-MM-DD` and European `DD.MM.YYYY`; an ISO timestamp's
+        // `T` leaves no word boundary after the day, so it never matches,
+        // and a space-separated one is rejected by the context check
+        pattern: r"\b(?:(?:19|20)\d{2}-(?:0[1-9]|1[0-2])-(?:0[1-9]|[12]\d|3[01])|(?:0[1-9]|[12]\d|3[01])\.(?:0[1-9]|1[0-2])\.(?:19|20)\d{2})\b",
+        confidence: 1.0,
+        validator: Some(is_calendar_date),
+        context: Some(birth_date_in_context),
+    },
+    BuiltinPattern {
+        pii_type: PIIType::DateOfBirth,
+        // `January 15, 1990`, `Jan. 15 1990` or `15 January 1990`
+        pattern: r"\b(?i:(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]*\.?\s+(?:0?[1-9]|[12]\d|3[01]),?|(?:0?[1-9]|[12]\d|3[01])\s+(?:Jan|Feb|Mar|Apr|May|Jun|Jul|Aug|Sep|Oct|Nov|Dec)[a-z]*\.?,?)\s+(?:19|20)\d{2}\b",
+        confidence: 1.0,
+        validator: Some(is_calendar_date),
+        context: Some(birth_date_in_context),
+    },
+    BuiltinPattern {
+        pii_type: PIIType::Passport,
+        pattern: r"\b[A-Z]{1,2}\d{6,9}\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::DriversLicense,
+        pattern: r"\b[A-Z]\d{7,14}\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::Vin,
+        // I, O and Q are never used; requiring a letter and a digit
+        // leaves all-digit runs to `BankAccount`
+        pattern: r"\b[A-HJ-NPR-Z0-9]{17}\b",
+        confidence: 1.0,
+        validator: Some(has_letter_and_digit),
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::CryptoWallet,
+        // Base58 leaves out 0, O, I and l and bech32 also 1, b, i and o;
+        // the validator keeps digit runs and hex hashes out of the Bitcoin forms
+        pattern: r"\b(?:[13][1-9A-HJ-NP-Za-km-z]{25,34}|bc1[02-9ac-hj-np-z]{11,71}|BC1[02-9AC-HJ-NP-Z]{11,71}|0x[0-9a-fA-F]{40})\b",
+        confidence: 1.0,
+        validator: Some(wallet_shaped),
+        context: None,
+    },
+    BuiltinPattern {
+        pii_type: PIIType::BankAccount,
+        pattern: r"\b\d{8,17}\b",
+        confidence: 1.0,
+        validator: None,
+        context: None,
+    },
+];
+
+/// Card network, identified from the leading digits and length of a card number
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CardBrand {
+    Visa,
+    Mastercard,
+    Amex,
+    DinersClub,
+    Discover,
+    Jcb,
+    UnionPay,
+}
+
+impl CardBrand {
+    /// Brand of a card number given as bare digits, if its issuer prefix and
+    /// length match a known network
+    pub(crate) fn of_digits(digits: &str) -> Option<CardBrand> {
+        let len = digits.len();
+        let prefix = |n: usize| digits.get(..n).and_then(|p| p.parse::<u32>().ok());
+        let in_range = |n: usize, lo: u32, hi: u32| prefix(n).is_some_and(|p| (lo..=hi).contains(&p));
+
+        if digits.starts_with('4') && matches!(len, 13 | 16 | 19) {
+            Some(CardBrand::Visa)
+        } else if (in_range(2, 51, 55) || in_range(4, 2221, 2720)) && len == 16 {
+            Some(CardBrand::Mastercard)
+        } else if matches!(prefix(2), Some(34 | 37)) && len == 15 {
+            Some(CardBrand::Amex)
+        } else if (in_range(3, 300, 305) || matches!(prefix(2), Some(36 | 38 | 39))) && len == 14 {
+            Some(CardBrand::DinersClub)
+        } else if (prefix(4) == Some(6011) || prefix(2) == Some(65) || in_range(3, 644, 649))
+            && (16..=19).contains(&len)
+        {
+            Some(CardBrand::Discover)
+        } else if in_range(4, 3528, 3589) && (16..=19).contains(&len) {
+            Some(CardBrand::Jcb)
+        } else if prefix(2) == Some(62) && (16..=19).contains(&len) {
+            Some(CardBrand::UnionPay)
+        } else {
+            None
+        }
+    }
+}
+
+/// Whether `value` is a Luhn-valid number of a known card brand and length
+fn card_number_valid(value: &str) -> bool {
+    let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+    luhn_valid(&digits) && CardBrand::of_digits(&digits).is_some()
+}
+
+/// Whether the digits of `value` pass the Luhn checksum used by card numbers
+pub(crate) fn luhn_valid(value: &str) -> bool {
+    let digits: Vec<u32> = value.chars().filter_map(|c| c.to_digit(10)).collect();
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| match (i % 2 == 1, d * 2) {
+            (true, doubled) if doubled > 9 => doubled - 9,
+            (true, doubled) => doubled,
+            (false, _) => d,
+        })
+        .sum();
+    !digits.is_empty() && sum % 10 == 0
+}
+
+/// Whether `value` holds at least one ASCII letter and one digit
+fn has_letter_and_digit(value: &str) -> bool {
+    value.chars().any(|c| c.is_ascii_alphabetic()) && value.chars().any(|c| c.is_ascii_digit())
+}
+
+/// Whether a `CryptoWallet` match could be an address: it holds a letter and
+/// a digit and, unless it is an `0x` address, isn't all hex digits the way
+/// MD5 hashes and dashless UUIDs are
+///
+/// With `std`, Bitcoin addresses must also carry a valid checksum.
+pub(crate) fn wallet_shaped(value: &str) -> bool {
+    has_letter_and_digit(value) && (value.starts_with("0x") || !value.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+/// Oldest age, in years, of a birth date outside `MM/DD/YYYY`
+const MAX_AGE_YEARS: i32 = 120;
+
+/// Youngest age, in years, of a birth date outside `MM/DD/YYYY` that no
+/// birth keyword precedes
+const MIN_AGE_YEARS: i32 = 18;
+
+/// How many bytes before a date are searched for a birth keyword
+const KEYWORD_WINDOW: usize = 24;
+
+/// Lowercase words that mark the date after them as a birth date
+const BIRTH_KEYWORDS: [&str; 4] = ["born", "birth", "dob", "d.o.b"];
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+/// Whether `value`, an ISO, dotted or written-month date, is a real day
+fn is_calendar_date(value: &str) -> bool {
+    let Some((year, month, day)) = parse_date(value) else {
+        return false;
+    };
+    let days_in_month = match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    (1..=12).contains(&month) && (1..=days_in_month).contains(&day)
+}
+
+/// Whether an ISO, dotted or written-month date over `start..end` of `text`
+/// reads as someone's birth date
+///
+/// These layouts also date log lines, releases and invoices, so unlike
+/// `MM/DD/YYYY` the date must lie between today and `MAX_AGE_YEARS` ago and
+/// either follow a birth keyword such as "born" or "DOB", or be at least
+/// `MIN_AGE_YEARS` old and not start a timestamp. Without `today` only the
+/// keyword is checked.
+fn birth_date_in_context(text: &str, start: usize, end: usize, today: Option<(i32, u32, u32)>) -> bool {
+    let Some(date) = parse_date(&text[start..end]) else {
+        return false;
+    };
+    let mut from = start.saturating_sub(KEYWORD_WINDOW);
+    while !text.is_char_boundary(from) {
+        from += 1;
+    }
+    let keyword = has_birth_keyword(&text[from..start]);
+    let Some(today) = today else {
+        return keyword;
+    };
+    if date > today {
+        return false;
+    }
+    let age = today.0 - date.0 - i32::from((today.1, today.2) < (date.1, date.2));
+    age <= MAX_AGE_YEARS && (keyword || (age >= MIN_AGE_YEARS && !starts_with_time(&text[end..])))
+}
+
+/// Whether `text` holds one of `BIRTH_KEYWORDS` at the start of a word, in
+/// any case
+fn has_birth_keyword(text: &str) -> bool {
+    let text = text.to_ascii_lowercase();
+    BIRTH_KEYWORDS.iter().any(|keyword| {
+        text.match_indices(keyword)
+            .any(|(i, _)| !text[..i].ends_with(|c: char| c.is_ascii_alphanumeric()))
+    })
+}
+
+/// Whether `text` starts with a time of day such as ` 12:00`, making the
+/// date before it part of a timestamp
+fn starts_with_time(text: &str) -> bool {
+    let text = text.trim_start_matches(|c: char| c == ',' || c.is_whitespace());
+    let hours = text.bytes().take_while(u8::is_ascii_digit).count();
+    let minutes = text.as_bytes().get(hours + 1..hours + 3);
+    (1..=2).contains(&hours)
+        && text.as_bytes().get(hours) == Some(&b':')
+        && minutes.is_some_and(|m| m.iter().all(u8::is_ascii_digit))
+}
+
+/// `(year, month, day)` of a date matched by a built-in `DateOfBirth`
+/// pattern, before checking the calendar
+fn parse_date(value: &str) -> Option<(i32, u32, u32)> {
+    if let [year, month, day] = value.split('-').collect::<Vec<_>>()[..] {
+        return Some((year.parse().ok()?, month.parse().ok()?, day.parse().ok()?));
+    }
+    let fields: Vec<&str> = value
+        .split(|c: char| c == '.' || c == ',' || c.is_whitespace())
+        .filter(|f| !f.is_empty())
+        .collect();
+    let [first, second, year] = fields[..] else {
+        return None;
+    };
+    let (month, day) = match (first.parse::<u32>(), second.parse::<u32>()) {
+        (Ok(day), Ok(month)) => (month, day),
+        (Ok(day), Err(_)) => (month_number(second)?, day),
+        (Err(_), Ok(day)) => (month_number(first)?, day),
+        (Err(_), Err(_)) => return None,
+    };
+    Some((year.parse().ok()?, month, day))
+}
+
+/// 1-based month of a full or three-letter English month name, in any case
+fn month_number(name: &str) -> Option<u32> {
+    let name = name.to_ascii_lowercase();
+    let index = MONTHS
+        .iter()
+        .position(|month| *month == name || (name.len() == 3 && month.starts_with(name.as_str())))?;
+    Some(index as u32 + 1)
+}
+
+/// Whether a `winner` match drops an overlapping `loser` match, given the
+/// types ranked by [`TorkConfig::numeric_precedence`](crate::TorkConfig::numeric_precedence)
+///
+/// Ranked types are compared by rank; otherwise only a `BankAccount` loses,
+/// to any other type.
+pub(crate) fn outranks(precedence: &[PIIType], winner: PIIType, loser: PIIType) -> bool {
+    let rank = |t: PIIType| precedence.iter().position(|&p| p == t);
+    match (rank(winner), rank(loser)) {
+        (Some(w), Some(l)) => w < l,
+        _ => loser == PIIType::BankAccount && winner != PIIType::BankAccount,
+    }
+}
+
+/// Whether a `BankAccount` match over `start..end` of `text` touches a
+/// hyphen, making it a digit group of a larger ID
+pub(crate) fn is_hyphenated_account(text: &str, pii_type: PIIType, start: usize, end: usize) -> bool {
+    let bytes = text.as_bytes();
+    pii_type == PIIType::BankAccount
+        && ((start > 0 && bytes[start - 1] == b'-') || bytes.get(end) == Some(&b'-'))
+}
+
+/// A match's type and byte range, so [`CoreMatch`] and
+/// [`PIIMatch`](crate::PIIMatch) share overlap resolution and redaction
+pub(crate) trait Span {
+    fn pii_type(&self) -> PIIType;
+    fn start(&self) -> usize;
+    fn end(&self) -> usize;
+}
+
+/// `matches` without `BankAccount` runs touching a hyphen or matches that an
+/// overlapping match [`outranks`], keeping the given order
+///
+/// One sweep in start order compares each match only with the earlier ones
+/// still open at its start, so non-overlapping input takes linear time after
+/// the sort.
+pub(crate) fn resolve_overlaps<M: Span>(text: &str, matches: Vec<M>, precedence: &[PIIType]) -> Vec<M> {
+    let mut keep: Vec<bool> = matches
+        .iter()
+        .map(|m| !is_hyphenated_account(text, m.pii_type(), m.start(), m.end()))
+        .collect();
+    let mut order: Vec<usize> = (0..matches.len()).collect();
+    order.sort_by_key(|&i| matches[i].start());
+    let mut open: Vec<usize> = Vec::new();
+    for i in order {
+        let m = &matches[i];
+        open.retain(|&j| matches[j].end() > m.start());
+        for &j in open.iter().filter(|&&j| matches[j].start() < m.end()) {
+            let x = &matches[j];
+            if outranks(precedence, x.pii_type(), m.pii_type()) {
+                keep[i] = false;
+            }
+            if outranks(precedence, m.pii_type(), x.pii_type()) {
+                keep[j] = false;
+            }
+        }
+        open.push(i);
+    }
+    matches
+        .into_iter()
+        .zip(keep)
+        .filter_map(|(m, keep)| keep.then_some(m))
+        .collect()
+}
+
+/// The matches to redact, ordered by start
+///
+/// Matches are claimed in the order given, so when two overlap the earlier
+/// one wins and the later one is left unredacted.
+pub(crate) fn claim<M: Span>(matches: &[M]) -> Vec<&M> {
+    // Claimed matches don't overlap, so ordered by start their ends never
+    // decrease and only the last one starting before `m` ends can overlap it
+    let mut ends: BTreeMap<usize, usize> = BTreeMap::new();
+    let mut claimed: Vec<&M> = Vec::new();
+    for m in matches {
+        if ends.range(..m.end()).next_back().is_some_and(|(_, &end)| end > m.start()) {
+            continue;
+        }
+        let end = ends.entry(m.start()).or_insert(m.end());
+        *end = (*end).max(m.end());
+        claimed.push(m);
+    }
+    claimed.sort_by_key(|m| m.start());
+    claimed
+}
+
+/// A built-in match found by [`CoreDetector`]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CoreMatch {
+    pub pii_type: PIIType,
+    /// Byte offset of the first matched byte
+    pub start: usize,
+    /// Byte offset just past the match
+    pub end: usize,
+    pub confidence: f32,
+}
+
+impl CoreMatch {
+    /// The matched text in `text`, the input it was found in
+    pub fn value<'a>(&self, text: &'a str) -> &'a str {
+        &text[self.start..self.end]
+    }
+}
+
+impl Span for CoreMatch {
+    fn pii_type(&self) -> PIIType {
+        self.pii_type
+    }
+
+    fn start(&self) -> usize {
+        self.start
+    }
+
+    fn end(&self) -> usize {
+        self.end
+    }
+}
+
+/// Matches and redacted text from [`CoreDetector::detect_pii`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoreDetectionResult {
+    pub has_pii: bool,
+    pub count: usize,
+    /// Ordered by start offset
+    pub matches: Vec<CoreMatch>,
+    pub redacted_text: String,
+}
+
+/// The built-in detectors compiled for use without `std`
+///
+/// Compiling takes a while, so build one and reuse it.
+#[derive(Debug, Clone)]
+pub struct CoreDetector {
+    regexes: Vec<Regex>,
+    today: Option<(i32, u32, u32)>,
+}
+
+impl CoreDetector {
+    /// Compile the built-in detectors
+    pub fn new() -> Self {
+        let regexes = BUILTIN_PATTERNS
+            .iter()
+            .map(|p| Regex::new(p.pattern).expect("built-in pattern compiles"))
+            .collect();
+        CoreDetector { regexes, today: None }
+    }
+
+    /// Check ISO, dotted and written-out birth dates against today's date,
+    /// given as `year`, `month` and `day`, as [`detect_pii`](crate::detect_pii)
+    /// does with the system clock
+    pub fn with_today(mut self, year: i32, month: u32, day: u32) -> Self {
+        self.today = Some((year, month, day));
+        self
+    }
+
+    /// Matches in `text` in detector priority order, before overlaps are
+    /// resolved
+    fn raw_matches(&self, text: &str) -> Vec<CoreMatch> {
+        let mut matches = Vec::new();
+        for (pattern, regex) in BUILTIN_PATTERNS.iter().zip(&self.regexes) {
+            if pattern.confidence < DEFAULT_MIN_CONFIDENCE {
+                continue;
+            }
+            // A match the validator rejects is skipped whole, as in `std`
+            for found in regex.find_iter(Input::new(text)) {
+                if pattern.validator.is_none_or(|v| v(&text[found.range()]))
+                    && pattern
+                        .context
+                        .is_none_or(|check| check(text, found.start(), found.end(), self.today))
+                {
+                    matches.push(CoreMatch {
+                        pii_type: pattern.pii_type,
+                        start: found.start(),
+                        end: found.end(),
+                        confidence: pattern.confidence,
+                    });
+                }
+            }
+        }
+        resolve_overlaps(text, matches, &DEFAULT_NUMERIC_PRECEDENCE)
+    }
+
+    /// Built-in matches in `text`, ordered by start offset
+    pub fn detect(&self, text: &str) -> Vec<CoreMatch> {
+        let mut matches = self.raw_matches(text);
+        matches.sort_by_key(|m| (m.start, m.end));
+        matches
+    }
+
+    /// Whether `text` contains any built-in PII
+    pub fn has_pii(&self, text: &str) -> bool {
+        !self.raw_matches(text).is_empty()
+    }
+
+    /// Detect PII in `text`, replacing each match with its type's
+    /// [`redaction`](PIIType::redaction) placeholder
+    ///
+    /// Where matches overlap, the earlier detector's is redacted.
+    pub fn detect_pii(&self, text: &str) -> CoreDetectionResult {
+        let mut matches = self.raw_matches(text);
+        let mut redacted_text = String::with_capacity(text.len());
+        let mut last = 0;
+        for m in claim(&matches) {
+            redacted_text.push_str(&text[last..m.start]);
+            redacted_text.push_str(m.pii_type.redaction());
+            last = m.end;
+        }
+        redacted_text.push_str(&text[last..]);
+
+        matches.sort_by_key(|m| (m.start, m.end));
+        CoreDetectionResult {
+            has_pii: !matches.is_empty(),
+            count: matches.len(),
+            matches,
+            redacted_text,
+        }
+    }
+}
+
+impl Default for CoreDetector {
+    fn default() -> Self {
+        CoreDetector::new()
+    }
+}
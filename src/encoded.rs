@@ -0,0 +1,39 @@
+//! Base64-encoded PII
+//!
+//! With [`TorkConfig::scan_encoded`](crate::TorkConfig::scan_encoded), long
+//! base64 runs are decoded and scanned again. A run whose decoded text holds
+//! PII is redacted whole as [`PIIType::EncodedPii`](crate::PIIType::EncodedPii).
+
+use base64::alphabet;
+use base64::engine::general_purpose::{GeneralPurpose, GeneralPurposeConfig};
+use base64::engine::DecodePaddingMode;
+use base64::Engine;
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Longest base64 run, in bytes, that is decoded and scanned
+///
+/// Longer runs are left as they are, so decoding never costs more than a
+/// bounded amount per run.
+pub const MAX_ENCODED_SCAN_LEN: usize = 64 * 1024;
+
+/// Shortest run considered; an encoded SSN is 16 bytes
+const MIN_ENCODED_LEN: usize = 16;
+
+/// Standard alphabet, with or without padding
+const ENGINE: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    GeneralPurposeConfig::new().with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+/// Base64 runs in `text` that decode to UTF-8, as `(start, end, decoded)`
+pub(crate) fn decoded_runs(text: &str) -> impl Iterator<Item = (usize, usize, String)> + '_ {
+    static RUN: OnceLock<Regex> = OnceLock::new();
+    let run = RUN.get_or_init(|| Regex::new(r"[A-Za-z0-9+/]+={0,2}").unwrap());
+    run.find_iter(text)
+        .filter(|m| (MIN_ENCODED_LEN..=MAX_ENCODED_SCAN_LEN).contains(&m.len()))
+        .filter_map(|m| {
+            let decoded = String::from_utf8(ENGINE.decode(m.as_str()).ok()?).ok()?;
+            Some((m.start(), m.end(), decoded))
+        })
+}
@@ -0,0 +1,88 @@
+//! Abstraction over "something that governs text"
+//!
+//! [`Governor`] is object-safe, so applications and the
+//! [middlewares](crate::middleware) can hold an
+//! `Arc<dyn Governor + Send + Sync>` and swap [`Tork`] for a
+//! [`NoOpGovernor`] in development or a stub in tests.
+
+use crate::{
+    detection_result_with, generate_receipt_id, hash_text, GovernanceAction, GovernanceReceipt, GovernanceResult,
+    Tork,
+};
+use chrono::Utc;
+
+/// Governs text, returning the action taken and its receipt
+pub trait Governor {
+    /// Apply governance to `input`
+    fn govern(&self, input: &str) -> GovernanceResult;
+
+    /// Govern a request body that couldn't be parsed, under
+    /// [`InvalidJsonPolicy::Deny`](crate::middleware::InvalidJsonPolicy::Deny)
+    ///
+    /// Defaults to [`govern`](Governor::govern) on the raw body. [`Tork`]
+    /// denies it without scanning.
+    fn govern_unparsed(&self, body: &str) -> GovernanceResult {
+        self.govern(body)
+    }
+}
+
+/// Governs with the current config through [`Tork::govern`]
+impl Governor for Tork {
+    fn govern(&self, input: &str) -> GovernanceResult {
+        Tork::govern(self, input)
+    }
+
+    fn govern_unparsed(&self, body: &str) -> GovernanceResult {
+        let pii = detection_result_with(Vec::new(), String::new(), Vec::new());
+        self.apply_action(body, &pii, GovernanceAction::Deny)
+    }
+}
+
+/// Policy version on receipts from [`NoOpGovernor`]
+pub const NO_OP_POLICY_VERSION: &str = "noop";
+
+/// [`Governor`] that allows everything unchanged without scanning it
+///
+/// Results carry a fresh receipt under [`NO_OP_POLICY_VERSION`], so code
+/// that logs receipt IDs keeps working, but nothing is chained or counted.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOpGovernor;
+
+impl Governor for NoOpGovernor {
+    fn govern(&self, input: &str) -> GovernanceResult {
+        let hash = hash_text(input);
+        GovernanceResult {
+            action: GovernanceAction::Allow,
+            output: input.to_string(),
+            pii: detection_result_with(Vec::new(), input.to_string(), Vec::new()),
+            receipt: GovernanceReceipt {
+                receipt_id: generate_receipt_id(),
+                timestamp: Utc::now(),
+                input_hash: hash.clone(),
+                output_hash: hash,
+                action: GovernanceAction::Allow,
+                policy_version: NO_OP_POLICY_VERSION.to_string(),
+                processing_time_ns: 0,
+                detection_time_ns: 0,
+                receipt_time_ns: 0,
+                session_context: None,
+                escalated: false,
+                prev_receipt_hash: None,
+                synthetic: false,
+                correlation_id: None,
+                tenant_id: None,
+            },
+            region: None,
+            industry: None,
+            session_context: None,
+            rationale: vec!["governance disabled by NoOpGovernor".to_string()],
+            escalated: false,
+            invalid_utf8: Vec::new(),
+            input_len: input.len(),
+            modified: false,
+            original: None,
+            budget_exceeded: false,
+            deny_reason: None,
+        }
+    }
+}
@@ -5,6 +5,7 @@
 //! ## Quick Start
 //!
 //! ```rust
+//! # #[cfg(feature = "std")] {
 //! use tork_governance::{Tork, GovernanceAction};
 //!
 //! let mut tork = Tork::new();
@@ -12,6 +13,7 @@
 //!
 //! assert_eq!(result.action, GovernanceAction::Redact);
 //! assert_eq!(result.output, "My SSN is [SSN_REDACTED]");
+//! # }
 //! ```
 //!
 //! ## Framework Middleware
@@ -23,91 +25,419 @@
 //! - **Rocket**: `tork_governance::middleware::rocket::TorkFairing`
 //!
 //! See the middleware module documentation for usage examples.
+//!
+//! ## `no_std`
+//!
+//! Without the default `std` feature the crate is `no_std` and needs only
+//! `alloc`. What remains is [`core_detect`], the built-in detectors with
+//! their validators and redaction; governance, receipts, timing and stats
+//! need `std`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
 
+pub mod core_detect;
+mod pii_type;
+
+pub use core_detect::{CardBrand, DEFAULT_MIN_CONFIDENCE};
+pub use pii_type::{PIIType, ParsePIITypeError};
+
+#[cfg(feature = "std")]
+mod encoded;
+#[cfg(feature = "std")]
+mod governor;
+#[cfg(feature = "std")]
+mod markup;
+#[cfg(feature = "metrics")]
+mod metrics;
+#[cfg(feature = "std")]
 pub mod middleware;
+#[cfg(feature = "std")]
+mod names;
+#[cfg(feature = "std")]
+mod normalize;
+#[cfg(feature = "std")]
+mod placeholder;
+#[cfg(feature = "std")]
+mod postal;
+#[cfg(feature = "std")]
+mod stream;
+#[cfg(feature = "std")]
+mod synthetic;
+#[cfg(feature = "tracing")]
+mod telemetry;
+#[cfg(feature = "std")]
+mod urls;
+#[cfg(feature = "std")]
+mod wallet;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
+#[cfg(feature = "std")]
 use chrono::{DateTime, Utc};
-use regex::Regex;
+#[cfg(feature = "std")]
+use regex::{Regex, RegexBuilder, RegexSet, RegexSetBuilder};
+#[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
-use std::collections::HashSet;
+#[cfg(feature = "std")]
+use hmac::{Hmac, Mac};
+#[cfg(feature = "std")]
+use sha2::digest::KeyInit;
+#[cfg(feature = "std")]
+use sha2::{Digest, Sha256, Sha512};
+#[cfg(feature = "std")]
+use std::borrow::Cow;
+#[cfg(feature = "std")]
+use std::cmp::Reverse;
+#[cfg(feature = "std")]
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
+#[cfg(feature = "std")]
+use std::fmt;
+#[cfg(feature = "std")]
+use std::io::Write;
+#[cfg(feature = "std")]
+use std::ops::Range;
+#[cfg(feature = "std")]
+use std::path::Path;
+#[cfg(feature = "std")]
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+#[cfg(feature = "std")]
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock, PoisonError, RwLock};
+#[cfg(feature = "std")]
+use std::time::Duration;
+// `std::time::Instant::now` panics on wasm32-unknown-unknown; `web_time` is
+// the same type on native targets and backed by `performance.now()` in browsers.
+#[cfg(all(feature = "std", not(feature = "wasm")))]
 use std::time::Instant;
+#[cfg(feature = "wasm")]
+use web_time::Instant;
+#[cfg(feature = "std")]
 use uuid::Uuid;
 
+#[cfg(feature = "std")]
+pub use encoded::MAX_ENCODED_SCAN_LEN;
+#[cfg(feature = "std")]
+pub use governor::{Governor, NoOpGovernor, NO_OP_POLICY_VERSION};
+#[cfg(feature = "std")]
+pub use markup::Markup;
+#[cfg(feature = "metrics")]
+pub use self::metrics::describe_metrics;
+#[cfg(feature = "std")]
+pub use names::{
+    NameDetector, GAZETTEER_NAME_CONFIDENCE, GIVEN_NAME_CONFIDENCE, TITLED_NAME_CONFIDENCE,
+};
+#[cfg(feature = "std")]
+pub use placeholder::was_previously_redacted;
+#[cfg(feature = "std")]
+pub use postal::PostalCodeDetector;
+#[cfg(feature = "std")]
+pub use stream::{StreamGovernor, MAX_STREAM_HOLDBACK};
+
 // ============================================================================
 // Types
 // ============================================================================
 
-/// Types of PII that can be detected
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum PIIType {
-    Ssn,
-    CreditCard,
-    Email,
-    Phone,
-    Address,
-    IpAddress,
-    DateOfBirth,
-    Passport,
-    DriversLicense,
-    BankAccount,
-}
-
-impl PIIType {
-    /// Get the redaction placeholder for this PII type
-    pub fn redaction(&self) -> &'static str {
-        match self {
-            PIIType::Ssn => "[SSN_REDACTED]",
-            PIIType::CreditCard => "[CARD_REDACTED]",
-            PIIType::Email => "[EMAIL_REDACTED]",
-            PIIType::Phone => "[PHONE_REDACTED]",
-            PIIType::Address => "[ADDRESS_REDACTED]",
-            PIIType::IpAddress => "[IP_REDACTED]",
-            PIIType::DateOfBirth => "[DOB_REDACTED]",
-            PIIType::Passport => "[PASSPORT_REDACTED]",
-            PIIType::DriversLicense => "[DL_REDACTED]",
-            PIIType::BankAccount => "[ACCOUNT_REDACTED]",
-        }
-    }
-}
-
 /// Governance action to take
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// `#[non_exhaustive]` for the same reason as [`PIIType`]: matches outside
+/// this crate need a wildcard arm.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[non_exhaustive]
 pub enum GovernanceAction {
     Allow,
     Deny,
+    #[default]
     Redact,
+    /// Redact, and flag the result for out-of-band human review
+    ///
+    /// The output is the same redacted text as `Redact`, so nothing leaks
+    /// downstream; the difference is `escalated` on the result and receipt,
+    /// which review tooling keys on. `Redact` implies no review.
     Escalate,
 }
 
-impl Default for GovernanceAction {
-    fn default() -> Self {
-        GovernanceAction::Redact
+#[cfg(feature = "std")]
+impl GovernanceAction {
+    /// The snake_case name used in serialized form (e.g. `redact`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GovernanceAction::Allow => "allow",
+            GovernanceAction::Deny => "deny",
+            GovernanceAction::Redact => "redact",
+            GovernanceAction::Escalate => "escalate",
+        }
+    }
+
+    /// Rank used when several types resolve to different actions; the most
+    /// severe wins (`Allow` 0, `Redact` 1, `Escalate` 2, `Deny` 3)
+    pub fn severity(&self) -> u8 {
+        match self {
+            GovernanceAction::Allow => 0,
+            GovernanceAction::Redact => 1,
+            GovernanceAction::Escalate => 2,
+            GovernanceAction::Deny => 3,
+        }
+    }
+
+    /// HTTP status a service should answer with: 200 for `Allow` and
+    /// `Redact`, 202 for `Escalate` (accepted pending review) and 403 for
+    /// `Deny`
+    pub fn http_status(&self) -> u16 {
+        match self {
+            GovernanceAction::Allow | GovernanceAction::Redact => 200,
+            GovernanceAction::Escalate => 202,
+            GovernanceAction::Deny => 403,
+        }
+    }
+}
+
+/// Writes [`as_str`](GovernanceAction::as_str), e.g. `redact`
+#[cfg(feature = "std")]
+impl fmt::Display for GovernanceAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Which policy rule resolved a call to `Deny`, see
+/// [`GovernanceResult::deny_reason`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DenyReason {
+    /// A [`TorkConfig::denylist`] term was present
+    Denylist,
+    /// Detected PII resolved to `Deny` under `type_actions` or `default_action`
+    Pii,
+    /// A match below `min_confidence`, under [`TorkConfig::uncertain_action`]
+    UncertainPii,
+    /// Matches covered more than [`TorkConfig::max_redaction_ratio`], under
+    /// `budget_action`
+    RedactionBudget,
+}
+
+/// What `output` contains when governance resolves to `Deny`
+///
+/// Prior to this setting a denied result always echoed the original input
+/// back in `output`. The default is now `Empty` so that a denied response
+/// never leaks the data it was denied for; use `Passthrough` to restore the
+/// old behavior.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DenyOutput {
+    /// Output is the original, unmodified input
+    Passthrough,
+    /// Output is an empty string
+    #[default]
+    Empty,
+    /// Output is the redacted text, as if the action were `Redact`
+    RedactedAnyway,
+    /// Output is a fixed message
+    Message(String),
+}
+
+#[cfg(feature = "std")]
+impl DenyOutput {
+    /// Produce the denied output for an input and its redacted form
+    pub fn apply(&self, input: &str, redacted: &str) -> String {
+        match self {
+            DenyOutput::Passthrough => input.to_string(),
+            DenyOutput::Empty => String::new(),
+            DenyOutput::RedactedAnyway => redacted.to_string(),
+            DenyOutput::Message(message) => message.clone(),
+        }
     }
 }
 
 /// A single PII match found in text
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PIIMatch {
     pub pii_type: PIIType,
     pub value: String,
     pub start_index: usize,
     pub end_index: usize,
+    /// JSON pointer to the string the match was found in, for structured
+    /// input (see [`Tork::govern_json`]). Indices are relative to that string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+    /// How certain the detector is that this is PII, from 0.0 to 1.0. Matches
+    /// below [`TorkConfig::min_confidence`] are discarded.
+    #[serde(default = "default_match_confidence")]
+    pub confidence: f32,
+    /// Named capture groups of the pattern that matched, in order, for
+    /// [`RedactionStyle::GroupMask`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<MatchGroup>,
+}
+
+/// A named capture group within a [`PIIMatch`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchGroup {
+    pub name: String,
+    /// Byte offset of the group within the match's `value`
+    pub start: usize,
+    /// Byte offset just past the group within the match's `value`
+    pub end: usize,
+}
+
+#[cfg(feature = "std")]
+fn default_match_confidence() -> f32 {
+    1.0
+}
+
+#[cfg(feature = "std")]
+impl PIIMatch {
+    /// Whether this SSN-format match is an ITIN
+    ///
+    /// Individual Taxpayer Identification Numbers share the SSN layout and are
+    /// reported as [`PIIType::Ssn`], but start with 9 and have a middle group
+    /// of 50-65, 70-88, 90-92 or 94-99, ranges never issued as SSNs.
+    pub fn is_itin(&self) -> bool {
+        if self.pii_type != PIIType::Ssn {
+            return false;
+        }
+        let digits: Vec<u32> = self.value.chars().filter_map(|c| c.to_digit(10)).collect();
+        if digits.len() != 9 || digits[0] != 9 {
+            return false;
+        }
+        let group = digits[3] * 10 + digits[4];
+        matches!(group, 50..=65 | 70..=88 | 90..=92 | 94..=99)
+    }
+
+    /// Network of this credit card match, when its prefix and length
+    /// identify one
+    pub fn card_brand(&self) -> Option<CardBrand> {
+        if self.pii_type != PIIType::CreditCard {
+            return None;
+        }
+        CardBrand::of_digits(&normalize_value(self.pii_type, &self.value))
+    }
+
+    /// Canonical form of `value`, for deduplication and allowlisting
+    ///
+    /// Numeric types drop separators, emails are lowercased and phone numbers
+    /// are rendered in E.164, assuming `+1` for ten-digit numbers. Other types
+    /// are returned unchanged.
+    pub fn normalized(&self) -> String {
+        normalize_value(self.pii_type, &self.value)
+    }
+}
+
+#[cfg(feature = "std")]
+impl core_detect::Span for PIIMatch {
+    fn pii_type(&self) -> PIIType {
+        self.pii_type
+    }
+
+    fn start(&self) -> usize {
+        self.start_index
+    }
+
+    fn end(&self) -> usize {
+        self.end_index
+    }
+}
+
+/// Canonical form of a value of the given type, see [`PIIMatch::normalized`]
+#[cfg(feature = "std")]
+fn normalize_value(pii_type: PIIType, value: &str) -> String {
+    let digits = || value.chars().filter(char::is_ascii_digit).collect::<String>();
+    match pii_type {
+        PIIType::Ssn | PIIType::Ein | PIIType::CreditCard | PIIType::BankAccount => digits(),
+        PIIType::Email => value.to_lowercase(),
+        PIIType::Vin => value.to_ascii_uppercase(),
+        PIIType::Phone => {
+            let digits = digits();
+            if digits.len() == 10 {
+                format!("+1{}", digits)
+            } else {
+                format!("+{}", digits)
+            }
+        }
+        _ => value.to_string(),
+    }
 }
 
 /// Result of PII detection
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PIIDetectionResult {
     pub has_pii: bool,
     pub types: Vec<PIIType>,
     pub count: usize,
+    /// Matches ordered by `start_index`, then `end_index`. For structured
+    /// input they follow document order instead.
     pub matches: Vec<PIIMatch>,
     pub redacted_text: String,
+    /// Substitutions that turn the input into `redacted_text`, in text order.
+    /// Empty for structured input, where matches carry a `location` instead.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub spans: Vec<RedactionSpan>,
+    /// Whether detection stopped at [`TorkConfig::max_matches`]; PII past
+    /// the limit was neither reported nor redacted
+    #[serde(default)]
+    pub truncated: bool,
+}
+
+#[cfg(feature = "std")]
+impl PIIDetectionResult {
+    /// Matches grouped by type, each group in `matches` order
+    pub fn by_type(&self) -> HashMap<PIIType, Vec<&PIIMatch>> {
+        let mut groups: HashMap<PIIType, Vec<&PIIMatch>> = HashMap::new();
+        for m in &self.matches {
+            groups.entry(m.pii_type).or_default().push(m);
+        }
+        groups
+    }
+
+    /// Number of matches of each type, for summaries that shouldn't carry values
+    pub fn counts_by_type(&self) -> HashMap<PIIType, usize> {
+        let mut counts = HashMap::new();
+        for m in &self.matches {
+            *counts.entry(m.pii_type).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// One substitution made by redaction, in original-text byte offsets
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RedactionSpan {
+    pub original_start: usize,
+    pub original_end: usize,
+    pub original_value: String,
+    pub replacement: String,
+}
+
+/// Lightweight detection summary from [`Tork::scan`] and [`Tork::detect_cow`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PIIScanResult {
+    pub has_pii: bool,
+    pub count: usize,
+    pub types: Vec<PIIType>,
+}
+
+/// Detection result from [`Tork::detect_with_timeout`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimedDetectionResult {
+    /// Matches found before the deadline
+    pub pii: PIIDetectionResult,
+    /// Whether the deadline passed before every detector ran
+    pub timed_out: bool,
 }
 
 /// Cryptographic receipt for audit trail
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GovernanceReceipt {
     pub receipt_id: String,
@@ -116,17 +446,144 @@ pub struct GovernanceReceipt {
     pub output_hash: String,
     pub action: GovernanceAction,
     pub policy_version: String,
+    /// Total time of the call, `detection_time_ns + receipt_time_ns`
     pub processing_time_ns: u64,
+    /// Time spent detecting PII, choosing the action and building the output
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub detection_time_ns: u64,
+    /// Time spent hashing the input and output and deriving the receipt ID
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub receipt_time_ns: u64,
     /// Agent/session context when provided.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session_context: Option<SessionContext>,
+    /// Whether the call was escalated for human review
+    #[serde(default)]
+    pub escalated: bool,
+    /// [`chain_hash`](GovernanceReceipt::chain_hash) of the receipt issued
+    /// before this one by the same instance; `None` for the first
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_receipt_hash: Option<String>,
+    /// Whether the output holds fake values from
+    /// [`RedactionStyle::Synthetic`] in place of the detected PII
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub synthetic: bool,
+    /// Caller's request or trace ID, from [`GovernContext::correlation_id`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+    /// Tenant the call was made for, from [`GovernContext::tenant_id`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<String>,
+}
+
+#[cfg(feature = "std")]
+impl GovernanceReceipt {
+    /// Deterministic encoding of this receipt, for hashing and signing
+    ///
+    /// The receipt is rendered as JSON with object keys sorted
+    /// lexicographically at every level and no insignificant whitespace, so
+    /// the bytes depend only on field values, not on serde's field or map
+    /// ordering. Use these bytes, never `serde_json::to_vec`, as the input to
+    /// any HMAC or signature over a receipt.
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let value = serde_json::to_value(self).expect("receipt serializes to JSON");
+        let mut out = Vec::new();
+        write_canonical_json(&value, &mut out);
+        out
+    }
+
+    /// SHA-256 of [`canonical_bytes`](GovernanceReceipt::canonical_bytes),
+    /// as linked from the next receipt's `prev_receipt_hash`
+    ///
+    /// Walking a receipt log and comparing each `prev_receipt_hash` with the
+    /// previous entry's `chain_hash` detects dropped or altered receipts.
+    pub fn chain_hash(&self) -> String {
+        let bytes = self.canonical_bytes();
+        hash_text(std::str::from_utf8(&bytes).expect("canonical JSON is UTF-8"))
+    }
+
+    /// The fields needed to audit this receipt, for space-constrained storage
+    ///
+    /// Drops timing, session context and the `escalated` flag (implied by
+    /// `action`), and records `chain_hash` so a log of compact receipts can
+    /// still be walked link by link.
+    pub fn compact(&self) -> CompactReceipt {
+        CompactReceipt {
+            receipt_id: self.receipt_id.clone(),
+            timestamp: self.timestamp,
+            input_hash: self.input_hash.clone(),
+            output_hash: self.output_hash.clone(),
+            action: self.action,
+            policy_version: self.policy_version.clone(),
+            prev_receipt_hash: self.prev_receipt_hash.clone(),
+            chain_hash: self.chain_hash(),
+        }
+    }
+}
+
+/// Whether a receipt timing is unset, so receipts from before it existed
+/// keep their canonical bytes
+#[cfg(feature = "std")]
+fn is_zero(ns: &u64) -> bool {
+    *ns == 0
+}
+
+/// Smaller form of a [`GovernanceReceipt`], see [`GovernanceReceipt::compact`]
+///
+/// The full receipt can't be rebuilt from it, so `chain_hash` is carried
+/// rather than recomputed.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompactReceipt {
+    pub receipt_id: String,
+    pub timestamp: DateTime<Utc>,
+    pub input_hash: String,
+    pub output_hash: String,
+    pub action: GovernanceAction,
+    pub policy_version: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev_receipt_hash: Option<String>,
+    pub chain_hash: String,
+}
+
+/// Write `value` as JSON with sorted object keys and no whitespace
+#[cfg(feature = "std")]
+fn write_canonical_json(value: &serde_json::Value, out: &mut Vec<u8>) {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push(b'{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                write_canonical_json(&serde_json::Value::String(key.clone()), out);
+                out.push(b':');
+                write_canonical_json(item, out);
+            }
+            out.push(b'}');
+        }
+        serde_json::Value::Array(items) => {
+            out.push(b'[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                write_canonical_json(item, out);
+            }
+            out.push(b']');
+        }
+        scalar => out.extend(serde_json::to_vec(scalar).expect("scalar serializes to JSON")),
+    }
 }
 
 /// Agent/session context for multi-agent governance tracking.
 ///
 /// All fields are optional. When provided, they are included in the POST body
 /// to /api/v1/govern and returned in the receipt under `session_context`.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SessionContext {
     /// Identifier for the agent making the call.
     pub agent_id: Option<String>,
@@ -139,6 +596,7 @@ pub struct SessionContext {
 }
 
 /// Options for regional and industry-specific PII detection
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GovernOptions {
     pub region: Option<Vec<String>>,
@@ -147,249 +605,3221 @@ pub struct GovernOptions {
     pub session_context: Option<SessionContext>,
 }
 
+/// Per-request identifiers copied unchanged into the receipt by
+/// [`Tork::govern_with_context`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GovernContext {
+    /// Request or trace ID linking the receipt to distributed traces
+    pub correlation_id: Option<String>,
+    pub tenant_id: Option<String>,
+    /// Agent/session context, also returned on the result
+    pub session_context: Option<SessionContext>,
+}
+
 /// Result of governance operation
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GovernanceResult {
     pub action: GovernanceAction,
     pub output: String,
     pub pii: PIIDetectionResult,
     pub receipt: GovernanceReceipt,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub region: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub industry: Option<String>,
     /// Agent/session context when provided.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub session_context: Option<SessionContext>,
+    /// Human-readable reasons for `action`, naming types and policy rules
+    /// but never matched values
+    #[serde(default)]
+    pub rationale: Vec<String>,
+    /// Whether the action is `Escalate`, i.e. the output is redacted and the
+    /// call awaits human review
+    #[serde(default)]
+    pub escalated: bool,
+    /// Byte ranges of the raw input that weren't valid UTF-8 and were
+    /// replaced with U+FFFD, from [`Tork::govern_bytes`]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invalid_utf8: Vec<Range<usize>>,
+    /// Length in bytes of the governed input
+    #[serde(default)]
+    pub input_len: usize,
+    /// Whether `output` differs from the governed input, also returned by
+    /// [`was_modified`](GovernanceResult::was_modified)
+    #[serde(default)]
+    pub modified: bool,
+    /// The governed input, kept only under [`TorkConfig::retain_input`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub original: Option<String>,
+    /// Whether matches covered more of the input than
+    /// [`TorkConfig::max_redaction_ratio`], so `budget_action` applied
+    #[serde(default)]
+    pub budget_exceeded: bool,
+    /// The rule that decided a `Deny`; `None` for other actions and for a
+    /// `Deny` chosen by the caller, e.g. through [`Tork::apply_action`]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deny_reason: Option<DenyReason>,
+}
+
+#[cfg(feature = "std")]
+impl GovernanceResult {
+    /// Whether governing `original`'s output again changed nothing
+    ///
+    /// `reprocessed` is the result of governing `original.output`; it must
+    /// report no PII and return the same output.
+    pub fn is_idempotent(original: &GovernanceResult, reprocessed: &GovernanceResult) -> bool {
+        !reprocessed.pii.has_pii && reprocessed.output == original.output
+    }
+
+    /// HTTP status for this result, see [`GovernanceAction::http_status`]
+    pub fn recommended_status(&self) -> u16 {
+        self.action.http_status()
+    }
+
+    /// Whether the input was allowed through with no PII found
+    pub fn is_clean(&self) -> bool {
+        self.action == GovernanceAction::Allow && !self.pii.has_pii
+    }
+
+    /// Whether `output` differs from the input
+    ///
+    /// Holds for redacted results and for denials that don't pass the input
+    /// through. Reads [`modified`](GovernanceResult::modified), so a result
+    /// a monitoring middleware forwarded unchanged reports `false`.
+    pub fn was_modified(&self) -> bool {
+        self.modified
+    }
+
+    /// Substitutions that turn the input into `output`
+    ///
+    /// Empty unless `output` is the redacted text, e.g. when the input was
+    /// allowed through unchanged or denied with a message.
+    pub fn redaction_spans(&self) -> Vec<RedactionSpan> {
+        if self.output == self.pii.redacted_text {
+            self.pii.spans.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// One [`DETECTIONS_CSV_HEADER`] row per match, without a trailing newline
+    ///
+    /// Rows hold the receipt ID, type and byte offsets of each match but
+    /// never its value, so they can go to compliance reports as they are.
+    pub fn to_csv_rows(&self) -> Vec<String> {
+        self.pii
+            .matches
+            .iter()
+            .map(|m| {
+                format!(
+                    "{},{},{},{},{}",
+                    csv_field(&self.receipt.receipt_id),
+                    m.pii_type.as_str(),
+                    m.start_index,
+                    m.end_index,
+                    m.confidence
+                )
+            })
+            .collect()
+    }
+}
+
+/// Column names of [`GovernanceResult::to_csv_rows`]
+#[cfg(feature = "std")]
+pub const DETECTIONS_CSV_HEADER: &str = "receipt_id,pii_type,start,end,confidence";
+
+/// Write [`DETECTIONS_CSV_HEADER`] and the rows of every result to `writer`,
+/// one `\n`-terminated line each
+///
+/// Results without matches add no rows.
+#[cfg(feature = "std")]
+pub fn write_detections_csv<'a, W: Write>(
+    results: impl IntoIterator<Item = &'a GovernanceResult>,
+    mut writer: W,
+) -> std::io::Result<()> {
+    writeln!(writer, "{}", DETECTIONS_CSV_HEADER)?;
+    for result in results {
+        for row in result.to_csv_rows() {
+            writeln!(writer, "{}", row)?;
+        }
+    }
+    Ok(())
+}
+
+/// `value` as a CSV field, quoted with inner quotes doubled when it holds a
+/// comma, quote or line break
+#[cfg(feature = "std")]
+fn csv_field(value: &str) -> Cow<'_, str> {
+    if value.contains([',', '"', '\r', '\n']) {
+        Cow::Owned(format!("\"{}\"", value.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(value)
+    }
+}
+
+/// A user-supplied regex detector declared in configuration
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomPattern {
+    /// Identifier used in error messages
+    pub name: String,
+    /// Regular expression source, compiled when the policy is loaded
+    pub pattern: String,
+    /// Type reported for matches (default: `custom`)
+    #[serde(default = "default_custom_pattern_type")]
+    pub pii_type: PIIType,
+}
+
+#[cfg(feature = "std")]
+fn default_custom_pattern_type() -> PIIType {
+    PIIType::Custom
 }
 
 /// Configuration for Tork instance
+///
+/// Holds a [`DetectionConfig`] and a [`Policy`] side by side as flat fields;
+/// [`detection`](TorkConfig::detection), [`policy`](TorkConfig::policy) and
+/// [`from_parts`](TorkConfig::from_parts) convert between the two forms.
+#[cfg(feature = "std")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TorkConfig {
     pub policy_version: String,
     pub default_action: GovernanceAction,
+    /// Output produced when the action is `Deny` (default: `Empty`)
+    pub deny_output: DenyOutput,
+    /// Per-type actions overriding `default_action`. When several types are
+    /// detected, the most severe action wins (`deny` > `escalate` > `redact` > `allow`).
+    pub type_actions: HashMap<PIIType, GovernanceAction>,
+    /// Values that are never treated as PII, compared in normalized form (see
+    /// [`PIIMatch::normalized`]) so any formatting of a listed card or phone matches
+    pub allowlist: Vec<String>,
+    /// Regexes searched in each matched value; a match any of them finds is
+    /// never treated as PII, on top of `allowlist`. Anchor with `^`/`$` to
+    /// require the whole value, e.g. `@example\.test$` for a test domain.
+    pub allowlist_patterns: Vec<String>,
+    /// Terms that deny the input outright when present (case-insensitive)
+    pub denylist: Vec<String>,
+    /// Additional regex detectors, run after the built-in patterns
+    pub custom_patterns: Vec<CustomPattern>,
+    /// How receipt IDs are generated (default: `Random`)
+    pub receipt_id_strategy: ReceiptIdStrategy,
+    /// Matches with a lower confidence are discarded (default: 0.5). Fuzzy
+    /// detectors such as [`PIIType::Name`] report low confidences, so this
+    /// controls how aggressively they redact.
+    pub min_confidence: f32,
+    /// How matched spans are rendered in redacted text (default: `Placeholder`)
+    pub redaction_style: RedactionStyle,
+    /// Per-type placeholders replacing [`PIIType::redaction`], e.g.
+    /// `ssn = "{{ssn}}"` (default: none). [`validate`](TorkConfig::validate)
+    /// rejects a placeholder that would itself be detected as PII.
+    pub placeholders: HashMap<PIIType, String>,
+    /// Tag placeholders with `#` and the policy version, e.g.
+    /// `[SSN_REDACTED#1.0.0]`, so later layers can tell real redactions
+    /// from look-alike text with [`was_previously_redacted`] (default:
+    /// false). `FixedWidth`, `PreserveLength`, `Equilength` and `Synthetic`
+    /// output is never tagged.
+    pub mark_redactions: bool,
+    /// Run detection over an NFKC-normalized copy of the input, so PII
+    /// written with full-width characters (`１２３－４５－６７８９`) or with
+    /// combining marks is still found (default: false)
+    ///
+    /// Matches are mapped back to the original text, which is what is
+    /// redacted and reported. Without the `unicode-casefold` feature only
+    /// full-width ASCII forms and basic combining diacritics are handled.
+    /// [`Tork::matches_iter`] and [`StreamGovernor`] scan the text as given.
+    pub normalize_unicode: bool,
+    /// Also detect email addresses written with `at`/`dot` substitutions,
+    /// such as `john [at] example [dot] com` or `john(at)example.com`
+    /// (default: false)
+    ///
+    /// The substitutions must be bracketed, with `[]`, `()`, `{}` or `<>`,
+    /// and the address must end in a dotted domain with an alphabetic TLD,
+    /// so prose that merely uses the words "at" and "dot" isn't matched.
+    pub detect_obfuscated: bool,
+    /// Keep the governed input in [`GovernanceResult::original`] (default:
+    /// false)
+    ///
+    /// The input holds the PII that was redacted, so only turn this on where
+    /// results are handled as sensitively as the input itself. Without it,
+    /// results carry only `input_len` and `modified`.
+    pub retain_input: bool,
+    /// Skip building the redacted text when the action doesn't output it
+    /// (default: false)
+    ///
+    /// Under `Allow`, or `Deny` with any `deny_output` but `RedactedAnyway`,
+    /// detection stops once the types and action are known, leaving
+    /// [`PIIDetectionResult::redacted_text`] empty and `spans` unset.
+    /// Matches, output and receipt are unaffected. Applies to text and
+    /// markup governance; JSON is redacted leaf by leaf as it is scanned.
+    pub receipt_only: bool,
+    /// Seed for [`RedactionStyle::Synthetic`] values, so the same input
+    /// always gets the same fakes (default: none, a random seed per call)
+    pub synthetic_seed: Option<u64>,
+    /// Types that are detected (default: all). Built-in and configured
+    /// patterns for other types are not run at all.
+    pub enabled_types: HashSet<PIIType>,
+    /// Algorithm for receipt input and output hashes (default: `Sha256`)
+    pub hash_algo: HashAlgo,
+    /// Secret key for receipt hashes (default: none)
+    ///
+    /// When set, input and output hashes are keyed (see [`hash_text_keyed`])
+    /// so that a leaked receipt can't be brute-forced back to a short input
+    /// such as an SSN. Load it from a secret store: it is never serialized
+    /// with the config. Keep it stable, since hashes made under different
+    /// salts can't be compared.
+    #[serde(skip_serializing)]
+    pub hash_salt: Option<Vec<u8>>,
+    /// Which type keeps an overlapping span when several numeric types match
+    /// it, earliest first (default: `Ssn`, `CreditCard`, `Phone`,
+    /// `BankAccount`)
+    ///
+    /// A listed type drops any overlapping match of a type listed after it,
+    /// and `BankAccount` also gives way to every type not listed.
+    pub numeric_precedence: Vec<PIIType>,
+    /// Reject numeric matches that can't be real values (default: false)
+    ///
+    /// `BankAccount` runs of one repeated digit (`11111111`) or of consecutive
+    /// digits (`12345678`, `98765432`) are dropped, as are `Vin` matches
+    /// whose check digit (position 9) is wrong. Only North American VINs
+    /// (first character `1`-`5`) are required to carry a check digit, so
+    /// other VINs are kept without one. `CryptoWallet` matches need a
+    /// base58check, bech32 or, for mixed-case Ethereum addresses, EIP-55
+    /// checksum.
+    pub validate_checksums: bool,
+    /// Stop detecting after this many matches (default: `None`, unlimited)
+    ///
+    /// Bounds the cost of adversarial input packed with PII-like tokens.
+    /// Detectors run in priority order and the matches found so far are
+    /// still redacted, but anything past the limit is left in the output, so
+    /// a truncated result (see [`PIIDetectionResult::truncated`]) may still
+    /// contain PII. JSON and markup input apply the limit to each string or
+    /// text run.
+    pub max_matches: Option<usize>,
+    /// Decode base64 runs and redact any whose decoded text contains PII
+    /// (default: false)
+    ///
+    /// Such a run is reported as one [`PIIType::EncodedPii`] match covering
+    /// the whole blob. Runs longer than [`MAX_ENCODED_SCAN_LEN`] bytes are
+    /// not decoded.
+    pub scan_encoded: bool,
+    /// What may border a built-in match (default: `Default`)
+    pub boundary_mode: BoundaryMode,
+    /// Action applied when a match falls below `min_confidence` but at or
+    /// above `uncertain_floor` (default: `Allow`, i.e. ignored)
+    ///
+    /// Such a match is still not redacted; the action is combined with the
+    /// others and the most severe wins, so `Escalate` sends uncertain input
+    /// for review instead of letting it through.
+    pub uncertain_action: GovernanceAction,
+    /// Lowest confidence that counts as uncertain rather than no match
+    /// (default: 0.0)
+    pub uncertain_floor: f32,
+    /// Largest share of the input, in bytes, that may be redacted before
+    /// `budget_action` applies (default: `None`, no limit)
+    ///
+    /// Input that is mostly PII is often garbage or a sign of an overzealous
+    /// pattern. The ratio is the total length of the matches over the input
+    /// length, and exceeding it sets [`GovernanceResult::budget_exceeded`].
+    pub max_redaction_ratio: Option<f32>,
+    /// Action applied when `max_redaction_ratio` is exceeded (default:
+    /// `Deny`); like `uncertain_action`, the most severe action wins
+    pub budget_action: GovernanceAction,
+    /// How [`Tork::govern_bytes`] decodes invalid UTF-8 (default: `Lossy`)
+    pub utf8_mode: Utf8Mode,
+}
+
+/// How [`Tork::govern_bytes`] treats input that isn't valid UTF-8
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Utf8Mode {
+    /// Replace each invalid sequence with U+FFFD and govern the result,
+    /// listing the sequences in [`GovernanceResult::invalid_utf8`]
+    #[default]
+    Lossy,
+    /// Reject the input with [`Error::InvalidUtf8`]
+    Strict,
+}
+
+/// What may border a match of a built-in pattern
+///
+/// Applies to every built-in regex pattern except `Address`, which keeps its
+/// word boundaries. Names, postal codes and custom patterns are unaffected.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BoundaryMode {
+    /// The regex word boundary `\b`: a match can't touch a letter, digit or
+    /// underscore, so `user_123-45-6789` is not an SSN
+    #[default]
+    Default,
+    /// A match must be bordered by whitespace, the start or end of the
+    /// text, or sentence punctuation (`.,;:!?'"()[]{}<>`); `x/123-45-6789`
+    /// and `id_123-45-6789` don't match
+    Strict,
+    /// A match can't touch a letter or digit but may touch anything else,
+    /// so `id_123-45-6789_x` matches
+    Lenient,
+}
+
+#[cfg(feature = "std")]
+impl BoundaryMode {
+    /// Whether `c`, the character just outside a match (`None` at the edge
+    /// of the text), may border it
+    fn allows(self, c: Option<char>) -> bool {
+        match (self, c) {
+            (_, None) => true,
+            (BoundaryMode::Default, Some(c)) => !(c.is_alphanumeric() || c == '_'),
+            (BoundaryMode::Strict, Some(c)) => c.is_whitespace() || ".,;:!?'\"()[]{}<>".contains(c),
+            (BoundaryMode::Lenient, Some(c)) => !c.is_alphanumeric(),
+        }
+    }
+
+    /// Whether the match `text[start..end]` is bordered as this mode requires
+    fn borders(self, text: &str, start: usize, end: usize) -> bool {
+        self.allows(text[..start].chars().next_back()) && self.allows(text[end..].chars().next())
+    }
 }
 
+/// How matched spans are rendered in redacted text
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactionStyle {
+    /// The type's placeholder, e.g. `[EMAIL_REDACTED]`
+    #[default]
+    Placeholder,
+    /// The placeholder with a per-type index, e.g. `[EMAIL_REDACTED:2]`
+    ///
+    /// Each distinct value takes the next index for its type in order of
+    /// appearance, so repeats of one email share a number. Numbering restarts
+    /// on every governance call and spans the whole document in
+    /// [`Tork::govern_json`].
+    Indexed,
+    /// Keep the named capture groups listed in `keep` and replace the rest of
+    /// the match with the type's placeholder
+    ///
+    /// Built-in patterns name their parts: `local` and `domain` for emails,
+    /// `country`, `area`, `exchange` and `line` for phone numbers, and `iin`,
+    /// `middle` and `last4` for cards. With `keep = ["domain"]`,
+    /// `john.doe@corp.com` becomes `[EMAIL_REDACTED]@corp.com`. Adjacent
+    /// masked groups share one placeholder and separators outside them are
+    /// kept. Matches keeping none of their groups, including those from
+    /// patterns without named groups, are replaced whole.
+    GroupMask { keep: Vec<String> },
+    /// Exactly `width` copies of `fill`, whatever the type or length of the
+    /// match, for fixed-width logs and to hide value lengths
+    FixedWidth { width: usize, fill: char },
+    /// The placeholder padded with `*` to the match's length in characters,
+    /// so redaction never shifts columns
+    ///
+    /// Matches shorter than the placeholder become all `*`.
+    PreserveLength,
+    /// The placeholder padded with `*` or cut short to the match's length in
+    /// bytes, so offsets into the output stay aligned with the input, e.g.
+    /// for token positions of a model the text is sent to
+    ///
+    /// `123-45-6789` becomes `[SSN_REDACT`. A placeholder is only cut at a
+    /// character boundary, with `*` making up the difference.
+    Equilength,
+    /// Replace the whole sentence around each match with
+    /// `[REDACTED_SENTENCE]`
+    ///
+    /// Sentences end at a newline or at `.`, `!` or `?` followed by
+    /// whitespace, so the dots inside emails and IPs don't split them. A
+    /// sentence with several matches is replaced once.
+    Sentence,
+    /// A made-up value of the same type, e.g. a Luhn-valid card of the same
+    /// brand or an `@example.com` email, for realistic training data
+    ///
+    /// Fakes pass the type's own validation, so they are detected again if
+    /// the output is governed a second time. Repeats of one value share a
+    /// fake within a call, and [`TorkConfig::synthetic_seed`] makes the
+    /// output reproducible. Receipts are flagged
+    /// [`synthetic`](GovernanceReceipt::synthetic).
+    Synthetic,
+}
+
+/// Replacement for a whole sentence under [`RedactionStyle::Sentence`]
+#[cfg(feature = "std")]
+pub const REDACTED_SENTENCE: &str = "[REDACTED_SENTENCE]";
+
+/// Hash algorithm for receipt input and output hashes
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HashAlgo {
+    #[default]
+    Sha256,
+    Sha512,
+    Blake3,
+}
+
+#[cfg(feature = "std")]
+impl HashAlgo {
+    /// Prefix identifying the algorithm in hash strings
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            HashAlgo::Sha256 => "sha256",
+            HashAlgo::Sha512 => "sha512",
+            HashAlgo::Blake3 => "blake3",
+        }
+    }
+}
+
+/// How receipt IDs are generated
+///
+/// IDs always carry the `rcpt_` prefix followed by 32 hex characters.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReceiptIdStrategy {
+    /// A random UUID per call
+    #[default]
+    Random,
+    /// Derived from the input hash, policy version and a timestamp bucket of
+    /// `window_secs` seconds (0 means no time component)
+    ///
+    /// Re-running identical input under the same policy within one window
+    /// yields the same ID, making batch re-runs idempotent. The flip side is
+    /// that IDs are no longer unique per call: every call with the same input,
+    /// policy and window collides by design, so don't use them as a primary
+    /// key for individual governance events.
+    ContentDerived { window_secs: u64 },
+}
+
+#[cfg(feature = "std")]
 impl Default for TorkConfig {
     fn default() -> Self {
         TorkConfig {
             policy_version: "1.0.0".to_string(),
             default_action: GovernanceAction::Redact,
+            deny_output: DenyOutput::default(),
+            type_actions: HashMap::new(),
+            allowlist: Vec::new(),
+            allowlist_patterns: Vec::new(),
+            denylist: Vec::new(),
+            custom_patterns: Vec::new(),
+            receipt_id_strategy: ReceiptIdStrategy::default(),
+            min_confidence: DEFAULT_MIN_CONFIDENCE,
+            redaction_style: RedactionStyle::default(),
+            placeholders: HashMap::new(),
+            mark_redactions: false,
+            normalize_unicode: false,
+            detect_obfuscated: false,
+            retain_input: false,
+            receipt_only: false,
+            synthetic_seed: None,
+            enabled_types: PIIType::all().iter().copied().collect(),
+            hash_algo: HashAlgo::default(),
+            hash_salt: None,
+            numeric_precedence: core_detect::DEFAULT_NUMERIC_PRECEDENCE.to_vec(),
+            validate_checksums: false,
+            max_matches: None,
+            scan_encoded: false,
+            boundary_mode: BoundaryMode::default(),
+            uncertain_action: GovernanceAction::Allow,
+            uncertain_floor: 0.0,
+            max_redaction_ratio: None,
+            budget_action: GovernanceAction::Deny,
+            utf8_mode: Utf8Mode::default(),
         }
     }
 }
 
-/// Statistics for Tork instance
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct TorkStats {
-    pub total_calls: u64,
-    pub total_pii_detected: u64,
-    pub total_processing_time_ns: u64,
-    pub action_counts: ActionCounts,
-}
+#[cfg(feature = "std")]
+impl TorkConfig {
+    /// Parse a policy from TOML, compiling custom patterns to surface regex errors
+    pub fn from_toml_str(s: &str) -> Result<Self, Error> {
+        let config: TorkConfig = toml::from_str(s).map_err(|e| Error::Parse(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
 
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
-pub struct ActionCounts {
-    pub allow: u64,
-    pub deny: u64,
-    pub redact: u64,
-    pub escalate: u64,
-}
+    /// Parse a policy from JSON, compiling custom patterns to surface regex errors
+    pub fn from_json_str(s: &str) -> Result<Self, Error> {
+        let config: TorkConfig = serde_json::from_str(s).map_err(|e| Error::Parse(e.to_string()))?;
+        config.validate()?;
+        Ok(config)
+    }
 
-// ============================================================================
-// PII Patterns
-// ============================================================================
+    /// Check that every custom pattern compiles and reports an enabled type,
+    /// and that no placeholder is itself PII
+    pub fn validate(&self) -> Result<(), Error> {
+        let custom = compile_custom_patterns(&self.custom_patterns)?;
+        for (i, pattern) in self.allowlist_patterns.iter().enumerate() {
+            compile_allowlist_patterns([pattern])
+                .map_err(|e| pattern_error(&format!("allowlist_patterns[{}]", i), e))?;
+        }
+        if let Some(p) = self
+            .custom_patterns
+            .iter()
+            .find(|p| !self.enabled_types.contains(&p.pii_type))
+        {
+            return Err(Error::ConflictingType {
+                name: p.name.clone(),
+                pii_type: p.pii_type,
+            });
+        }
+        // A detected placeholder would be redacted again on every re-run
+        for (&pii_type, placeholder) in &self.placeholders {
+            if default_tork().scan(placeholder).has_pii
+                || custom.iter().any(|d| d.detect_iter(placeholder).next().is_some())
+            {
+                return Err(Error::InvalidPlaceholder {
+                    pii_type,
+                    placeholder: placeholder.clone(),
+                });
+            }
+        }
+        Ok(())
+    }
 
-struct PIIPattern {
-    pii_type: PIIType,
-    regex: Regex,
-}
+    /// Combine a detection config and a policy
+    pub fn from_parts(detection: DetectionConfig, policy: Policy) -> Self {
+        let DetectionConfig {
+            enabled_types,
+            custom_patterns,
+            allowlist,
+            allowlist_patterns,
+            min_confidence,
+            numeric_precedence,
+            validate_checksums,
+            max_matches,
+            scan_encoded,
+            boundary_mode,
+            normalize_unicode,
+            detect_obfuscated,
+            utf8_mode,
+        } = detection;
+        let Policy {
+            policy_version,
+            default_action,
+            type_actions,
+            denylist,
+            deny_output,
+            uncertain_action,
+            uncertain_floor,
+            max_redaction_ratio,
+            budget_action,
+            redaction_style,
+            placeholders,
+            mark_redactions,
+            synthetic_seed,
+            retain_input,
+            receipt_only,
+            receipt_id_strategy,
+            hash_algo,
+            hash_salt,
+        } = policy;
+        TorkConfig {
+            policy_version,
+            default_action,
+            deny_output,
+            type_actions,
+            allowlist,
+            allowlist_patterns,
+            denylist,
+            custom_patterns,
+            receipt_id_strategy,
+            min_confidence,
+            redaction_style,
+            placeholders,
+            mark_redactions,
+            normalize_unicode,
+            detect_obfuscated,
+            retain_input,
+            receipt_only,
+            synthetic_seed,
+            enabled_types,
+            hash_algo,
+            hash_salt,
+            numeric_precedence,
+            validate_checksums,
+            max_matches,
+            scan_encoded,
+            boundary_mode,
+            uncertain_action,
+            uncertain_floor,
+            max_redaction_ratio,
+            budget_action,
+            utf8_mode,
+        }
+    }
 
-fn get_pii_patterns() -> Vec<PIIPattern> {
-    vec![
-        PIIPattern {
-            pii_type: PIIType::Ssn,
-            regex: Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap(),
-        },
-        PIIPattern {
-            pii_type: PIIType::CreditCard,
-            regex: Regex::new(r"\b\d{4}[-\s]?\d{4}[-\s]?\d{4}[-\s]?\d{4}\b").unwrap(),
-        },
-        PIIPattern {
-            pii_type: PIIType::Email,
-            regex: Regex::new(r"\b[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,}\b").unwrap(),
-        },
-        PIIPattern {
-            pii_type: PIIType::Phone,
-            regex: Regex::new(r"\b(?:\+?1[-.\s]?)?\(?\d{3}\)?[-.\s]?\d{3}[-.\s]?\d{4}\b").unwrap(),
-        },
-        PIIPattern {
-            pii_type: PIIType::Address,
-            regex: Regex::new(r"(?i)\b\d{1,5}\s+\w+(?:\s+\w+)*\s+(?:Street|St|Avenue|Ave|Road|Rd|Boulevard|Blvd|Drive|Dr|Lane|Ln|Court|Ct|Way|Place|Pl)\b").unwrap(),
-        },
-        PIIPattern {
-            pii_type: PIIType::IpAddress,
-            regex: Regex::new(r"\b(?:(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\.){3}(?:25[0-5]|2[0-4][0-9]|[01]?[0-9][0-9]?)\b").unwrap(),
-        },
-        PIIPattern {
-            pii_type: PIIType::DateOfBirth,
-            regex: Regex::new(r"\b(?:0[1-9]|1[0-2])/(?:0[1-9]|[12]\d|3[01])/(?:19|20)\d{2}\b").unwrap(),
-        },
-        PIIPattern {
-            pii_type: PIIType::Passport,
-            regex: Regex::new(r"\b[A-Z]{1,2}\d{6,9}\b").unwrap(),
-        },
-        PIIPattern {
-            pii_type: PIIType::DriversLicense,
-            regex: Regex::new(r"\b[A-Z]\d{7,14}\b").unwrap(),
-        },
-        PIIPattern {
-            pii_type: PIIType::BankAccount,
-            regex: Regex::new(r"\b\d{8,17}\b").unwrap(),
-        },
-    ]
-}
+    /// The detection half of this config
+    pub fn detection(&self) -> DetectionConfig {
+        DetectionConfig {
+            enabled_types: self.enabled_types.clone(),
+            custom_patterns: self.custom_patterns.clone(),
+            allowlist: self.allowlist.clone(),
+            allowlist_patterns: self.allowlist_patterns.clone(),
+            min_confidence: self.min_confidence,
+            numeric_precedence: self.numeric_precedence.clone(),
+            validate_checksums: self.validate_checksums,
+            max_matches: self.max_matches,
+            scan_encoded: self.scan_encoded,
+            boundary_mode: self.boundary_mode,
+            normalize_unicode: self.normalize_unicode,
+            detect_obfuscated: self.detect_obfuscated,
+            utf8_mode: self.utf8_mode,
+        }
+    }
 
-// ============================================================================
-// Utility Functions
-// ============================================================================
+    /// The policy half of this config
+    pub fn policy(&self) -> Policy {
+        Policy {
+            policy_version: self.policy_version.clone(),
+            default_action: self.default_action,
+            type_actions: self.type_actions.clone(),
+            denylist: self.denylist.clone(),
+            deny_output: self.deny_output.clone(),
+            uncertain_action: self.uncertain_action,
+            uncertain_floor: self.uncertain_floor,
+            max_redaction_ratio: self.max_redaction_ratio,
+            budget_action: self.budget_action,
+            redaction_style: self.redaction_style.clone(),
+            placeholders: self.placeholders.clone(),
+            mark_redactions: self.mark_redactions,
+            synthetic_seed: self.synthetic_seed,
+            retain_input: self.retain_input,
+            receipt_only: self.receipt_only,
+            receipt_id_strategy: self.receipt_id_strategy,
+            hash_algo: self.hash_algo,
+            hash_salt: self.hash_salt.clone(),
+        }
+    }
 
-/// Generate SHA256 hash of text with prefix
-pub fn hash_text(text: &str) -> String {
-    let mut hasher = Sha256::new();
-    hasher.update(text.as_bytes());
-    let result = hasher.finalize();
-    format!("sha256:{}", hex::encode(result))
+    /// Placeholder for `pii_type`: the configured override, or
+    /// [`PIIType::redaction`]
+    pub fn placeholder(&self, pii_type: PIIType) -> &str {
+        self.placeholders
+            .get(&pii_type)
+            .map_or(pii_type.redaction(), String::as_str)
+    }
+
+    /// Action, rationale and, for `Deny`, the deciding rule for `input`
+    /// given the types detected in it
+    fn decide(
+        &self,
+        input: &str,
+        types: &[PIIType],
+        uncertain: Option<PIIType>,
+        over_budget: bool,
+    ) -> (GovernanceAction, Vec<String>, Option<DenyReason>) {
+        let denylist_hit = self.denylist_hit(input);
+        let mut rationale = self.rationale(types, denylist_hit, uncertain);
+        let (mut action, mut reason) = if denylist_hit.is_some() {
+            (GovernanceAction::Deny, DenyReason::Denylist)
+        } else {
+            (self.action_for(types), DenyReason::Pii)
+        };
+        if uncertain.is_some() && self.uncertain_action.severity() > action.severity() {
+            action = self.uncertain_action;
+            reason = DenyReason::UncertainPii;
+        }
+        if let Some(max) = self.max_redaction_ratio.filter(|_| over_budget) {
+            rationale.push(format!(
+                "redaction exceeds max_redaction_ratio={} and budget_action={}",
+                max,
+                self.budget_action.as_str()
+            ));
+            if self.budget_action.severity() > action.severity() {
+                action = self.budget_action;
+                reason = DenyReason::RedactionBudget;
+            }
+        }
+        (action, rationale, (action == GovernanceAction::Deny).then_some(reason))
+    }
+
+    /// Whether `matches` cover more of `input` than `max_redaction_ratio`
+    fn exceeds_redaction_budget(&self, input: &str, matches: &[PIIMatch]) -> bool {
+        let Some(max) = self.max_redaction_ratio else {
+            return false;
+        };
+        let redacted: usize = matches.iter().map(|m| m.value.len()).sum();
+        !input.is_empty() && redacted as f64 / input.len() as f64 > f64::from(max)
+    }
+
+    /// Whether `action` outputs the redacted text
+    fn outputs_redacted(&self, action: GovernanceAction) -> bool {
+        match action {
+            GovernanceAction::Redact | GovernanceAction::Escalate => true,
+            GovernanceAction::Deny => self.deny_output == DenyOutput::RedactedAnyway,
+            GovernanceAction::Allow => false,
+        }
+    }
+
+    /// Resolve the action for a set of detected types
+    fn action_for(&self, types: &[PIIType]) -> GovernanceAction {
+        types
+            .iter()
+            .map(|t| self.type_actions.get(t).copied().unwrap_or(self.default_action))
+            .max_by_key(GovernanceAction::severity)
+            .unwrap_or(GovernanceAction::Allow)
+    }
+
+    /// Whether a match survives the confidence threshold, enabled types,
+    /// allowlist and checksum validation, given `allowlist_patterns`
+    /// compiled as `allowlist`
+    fn accepts(&self, m: &PIIMatch, allowlist: &RegexSet) -> bool {
+        m.confidence >= self.min_confidence && self.admits(m, allowlist)
+    }
+
+    /// Whether a match is too unsure to redact but sure enough for
+    /// `uncertain_action`
+    fn is_uncertain(&self, m: &PIIMatch, allowlist: &RegexSet) -> bool {
+        m.confidence < self.min_confidence && m.confidence >= self.uncertain_floor && self.admits(m, allowlist)
+    }
+
+    /// Whether a `winner` match drops an overlapping `loser` match
+    ///
+    /// Types ranked in `numeric_precedence` are compared by rank; otherwise
+    /// only a `BankAccount` loses, to any other type.
+    fn outranks(&self, winner: PIIType, loser: PIIType) -> bool {
+        core_detect::outranks(&self.numeric_precedence, winner, loser)
+    }
+
+    /// Whether any type can outrank `pii_type`
+    fn can_be_outranked(&self, pii_type: PIIType) -> bool {
+        pii_type == PIIType::BankAccount
+            || self.numeric_precedence.iter().position(|&p| p == pii_type).is_some_and(|rank| rank > 0)
+    }
+
+    /// Whether a match passes every filter but the confidence threshold
+    fn admits(&self, m: &PIIMatch, allowlist: &RegexSet) -> bool {
+        self.enabled_types.contains(&m.pii_type)
+            && !self.is_allowlisted(m, allowlist)
+            && (!self.validate_checksums || checksum_valid(m))
+    }
+
+    /// Whether the match's value, in any formatting, is on the allowlist or
+    /// found by an allowlist pattern
+    fn is_allowlisted(&self, m: &PIIMatch, allowlist: &RegexSet) -> bool {
+        if allowlist.is_match(&m.value) {
+            return true;
+        }
+        if self.allowlist.is_empty() {
+            return false;
+        }
+        let normalized = m.normalized();
+        self.allowlist
+            .iter()
+            .any(|entry| *entry == m.value || normalize_value(m.pii_type, entry) == normalized)
+    }
+
+    /// Receipt hash of `text` under this config's algorithm and salt
+    fn receipt_hash(&self, text: &str) -> String {
+        match &self.hash_salt {
+            Some(salt) => hash_text_keyed(self.hash_algo, salt, text),
+            None => hash_text_with(self.hash_algo, text),
+        }
+    }
+
+    /// Reasons for the action taken on `types`, in [`PIIType::all`] order
+    fn rationale(
+        &self,
+        types: &[PIIType],
+        denied_term: Option<&str>,
+        uncertain: Option<PIIType>,
+    ) -> Vec<String> {
+        let mut rationale = Vec::new();
+        if let Some(term) = denied_term {
+            rationale.push(format!("denylist term '{}' present", term));
+        }
+        for t in PIIType::all().iter().filter(|t| types.contains(t)) {
+            rationale.push(match self.type_actions.get(t) {
+                Some(action) => format!("{} matched and type_action={}", t.as_str(), action.as_str()),
+                None => format!(
+                    "{} matched and default_action={}",
+                    t.as_str(),
+                    self.default_action.as_str()
+                ),
+            });
+        }
+        if let Some(t) = uncertain {
+            rationale.push(format!(
+                "uncertain {} match below min_confidence and uncertain_action={}",
+                t.as_str(),
+                self.uncertain_action.as_str()
+            ));
+        }
+        if rationale.is_empty() {
+            rationale.push("no PII detected".to_string());
+        }
+        rationale
+    }
+
+    /// First denylist term present in `input`, if any
+    fn denylist_hit(&self, input: &str) -> Option<&str> {
+        if self.denylist.is_empty() {
+            return None;
+        }
+        let folded = fold_case(input);
+        self.denylist
+            .iter()
+            .find(|term| !term.is_empty() && folded.contains(&fold_case(term)))
+            .map(|term| term.as_str())
+    }
+}
+
+/// What to look for and how: the detection half of a [`TorkConfig`]
+///
+/// Each field is the [`TorkConfig`] field of the same name. Swap it with
+/// [`Tork::set_detection_config`] to change detectors while keeping the
+/// [`Policy`].
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DetectionConfig {
+    pub enabled_types: HashSet<PIIType>,
+    pub custom_patterns: Vec<CustomPattern>,
+    pub allowlist: Vec<String>,
+    pub allowlist_patterns: Vec<String>,
+    pub min_confidence: f32,
+    pub numeric_precedence: Vec<PIIType>,
+    pub validate_checksums: bool,
+    pub max_matches: Option<usize>,
+    pub scan_encoded: bool,
+    pub boundary_mode: BoundaryMode,
+    pub normalize_unicode: bool,
+    pub detect_obfuscated: bool,
+    pub utf8_mode: Utf8Mode,
+}
+
+#[cfg(feature = "std")]
+impl Default for DetectionConfig {
+    fn default() -> Self {
+        TorkConfig::default().detection()
+    }
+}
+
+/// What to do with what was found: the action and output half of a
+/// [`TorkConfig`]
+///
+/// Each field is the [`TorkConfig`] field of the same name. Swap it with
+/// [`Tork::set_policy`] to change actions, redaction and receipts while
+/// keeping the compiled detectors.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Policy {
+    pub policy_version: String,
+    pub default_action: GovernanceAction,
+    pub type_actions: HashMap<PIIType, GovernanceAction>,
+    pub denylist: Vec<String>,
+    pub deny_output: DenyOutput,
+    pub uncertain_action: GovernanceAction,
+    pub uncertain_floor: f32,
+    pub max_redaction_ratio: Option<f32>,
+    pub budget_action: GovernanceAction,
+    pub redaction_style: RedactionStyle,
+    pub placeholders: HashMap<PIIType, String>,
+    pub mark_redactions: bool,
+    pub synthetic_seed: Option<u64>,
+    pub retain_input: bool,
+    pub receipt_only: bool,
+    pub receipt_id_strategy: ReceiptIdStrategy,
+    pub hash_algo: HashAlgo,
+    #[serde(skip_serializing)]
+    pub hash_salt: Option<Vec<u8>>,
+}
+
+#[cfg(feature = "std")]
+impl Default for Policy {
+    fn default() -> Self {
+        TorkConfig::default().policy()
+    }
+}
+
+/// Caseless form of `text` for keyword matching
+///
+/// With the `unicode-casefold` feature this is the Unicode compatibility
+/// caseless form: full case folding plus NFKD, so `STRASSE` matches `straße`,
+/// final and medial sigma compare equal, and precomposed and decomposed
+/// accents agree. Without the feature, `str::to_lowercase` is used.
+///
+/// Either way Turkish dotted `İ` and dotless `ı` then fold to plain `i`, so
+/// `CONFİDENTIAL` and `confıdential` match `confidential`. The catch is that
+/// an `i` with a combining dot above, as written in Lithuanian, matches a
+/// plain `i` too.
+#[cfg(feature = "unicode-casefold")]
+fn fold_case(text: &str) -> String {
+    use caseless::Caseless;
+    use unicode_normalization::UnicodeNormalization;
+    let folded: String = text
+        .nfd()
+        .default_case_fold()
+        .nfkd()
+        .default_case_fold()
+        .nfkd()
+        .collect();
+    fold_dotted_i(&folded)
+}
+
+#[cfg(all(feature = "std", not(feature = "unicode-casefold")))]
+fn fold_case(text: &str) -> String {
+    fold_dotted_i(&text.to_lowercase())
+}
+
+/// `folded` with `i` plus a combining dot above (how `İ` folds) and
+/// dotless `ı` replaced by `i`
+#[cfg(feature = "std")]
+fn fold_dotted_i(folded: &str) -> String {
+    folded.replace("i\u{307}", "i").replace('\u{131}', "i")
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors raised while loading, compiling or selecting a policy, or when
+/// governance denies a call
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum Error {
+    /// The policy file could not be read
+    Io(std::io::Error),
+    /// The policy document is not valid TOML/JSON for a `TorkConfig`
+    Parse(String),
+    /// A custom pattern failed to compile
+    InvalidPattern { name: String, message: String },
+    /// A custom pattern compiles to a program larger than
+    /// [`CUSTOM_PATTERN_SIZE_LIMIT`] bytes
+    PatternTooLarge { name: String, limit: usize },
+    /// A custom pattern reports a type that `enabled_types` disables, so it
+    /// would never run
+    ConflictingType { name: String, pii_type: PIIType },
+    /// No policy with this version is registered, see
+    /// [`Tork::govern_with_policy`]
+    UnknownPolicy { version: String },
+    /// Input to [`Tork::govern_bytes`] under [`Utf8Mode::Strict`] is not
+    /// valid UTF-8 from this byte offset
+    InvalidUtf8 { position: usize },
+    /// A configured placeholder is itself detected as PII
+    InvalidPlaceholder { pii_type: PIIType, placeholder: String },
+    /// Input to [`Tork::govern_url`] is not an absolute URL
+    InvalidUrl { message: String },
+    /// A call [`Tork::try_govern`] denied
+    Denied(GovernanceDenied),
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "failed to read policy: {}", e),
+            Error::Parse(msg) => write!(f, "failed to parse policy: {}", msg),
+            Error::InvalidPattern { name, message } => {
+                write!(f, "invalid custom pattern '{}': {}", name, message)
+            }
+            Error::PatternTooLarge { name, limit } => {
+                write!(f, "custom pattern '{}' exceeds the {} byte size limit", name, limit)
+            }
+            Error::ConflictingType { name, pii_type } => write!(
+                f,
+                "custom pattern '{}' reports {}, which enabled_types disables",
+                name,
+                pii_type.as_str()
+            ),
+            Error::UnknownPolicy { version } => write!(f, "no policy registered for version '{}'", version),
+            Error::InvalidUtf8 { position } => write!(f, "input is not valid UTF-8 at byte {}", position),
+            Error::InvalidPlaceholder { pii_type, placeholder } => write!(
+                f,
+                "placeholder '{}' for {} is itself detected as PII",
+                placeholder,
+                pii_type.as_str()
+            ),
+            Error::InvalidUrl { message } => write!(f, "invalid URL: {}", message),
+            Error::Denied(denied) => denied.fmt(f),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io(e) => Some(e),
+            Error::Denied(denied) => Some(denied),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(e: std::io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+/// A call [`Tork::try_govern`] denied, holding its full result
+///
+/// The call was governed, receipted and counted like any other, so the
+/// receipt is still there to log when `?` passes this error up.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct GovernanceDenied {
+    result: Box<GovernanceResult>,
+}
+
+#[cfg(feature = "std")]
+impl GovernanceDenied {
+    /// The denied call's result
+    pub fn result(&self) -> &GovernanceResult {
+        &self.result
+    }
+
+    /// Unwrap the denied call's result
+    pub fn into_result(self) -> GovernanceResult {
+        *self.result
+    }
+
+    /// Receipt issued for the denied call
+    pub fn receipt(&self) -> &GovernanceReceipt {
+        &self.result.receipt
+    }
+
+    /// Types of PII detected in the denied input
+    pub fn types(&self) -> &[PIIType] {
+        &self.result.pii.types
+    }
+}
+
+/// Names the receipt and detected types, never the matched values
+#[cfg(feature = "std")]
+impl fmt::Display for GovernanceDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "governance denied input (receipt {}", self.result.receipt.receipt_id)?;
+        for (i, pii_type) in self.types().iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { ", types: " } else { ", " }, pii_type.as_str())?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Like `Display`, leaving out the input, output and matched values
+#[cfg(feature = "std")]
+impl fmt::Debug for GovernanceDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GovernanceDenied")
+            .field("receipt_id", &self.result.receipt.receipt_id)
+            .field("types", &self.types())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GovernanceDenied {}
+
+#[cfg(feature = "std")]
+impl From<GovernanceDenied> for Error {
+    fn from(denied: GovernanceDenied) -> Self {
+        Error::Denied(denied)
+    }
+}
+
+/// Statistics for Tork instance
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TorkStats {
+    pub total_calls: u64,
+    pub total_pii_detected: u64,
+    pub total_processing_time_ns: u64,
+    pub action_counts: ActionCounts,
+    /// Number of matches seen per PII type
+    #[serde(default)]
+    pub type_counts: HashMap<PIIType, u64>,
+    /// Distribution of `processing_time_ns` across calls
+    #[serde(default)]
+    pub latency: LatencyHistogram,
+    /// Audit log lines that failed to write (see [`Tork::with_audit_sink`])
+    #[serde(default)]
+    pub audit_write_errors: u64,
+}
+
+#[cfg(feature = "std")]
+impl TorkStats {
+    /// Mean processing time per call, or `None` before the first call
+    pub fn mean_processing_time_ns(&self) -> Option<u64> {
+        self.total_processing_time_ns.checked_div(self.total_calls)
+    }
+
+    /// Processing time at percentile `p` (0-100), or `None` before the first call
+    ///
+    /// See [`LatencyHistogram::percentile`] for the precision guarantees.
+    pub fn percentile(&self, p: f64) -> Option<u64> {
+        self.latency.percentile(p)
+    }
+
+    /// The `n` most frequently detected types, most common first
+    ///
+    /// Ties are broken by declaration order of [`PIIType`].
+    pub fn top_types(&self, n: usize) -> Vec<(PIIType, u64)> {
+        let mut counts: Vec<(PIIType, u64)> = self.type_counts.iter().map(|(t, c)| (*t, *c)).collect();
+        counts.sort_by_key(|(t, c)| {
+            let order = PIIType::all().iter().position(|x| x == t);
+            (std::cmp::Reverse(*c), order)
+        });
+        counts.truncate(n);
+        counts
+    }
+}
+
+/// Log-linear histogram of nanosecond latencies with bounded memory
+///
+/// Values below 16 are stored exactly; larger values fall into one of 16
+/// linear sub-buckets per power of two, so a reported percentile is within
+/// 1/16 (6.25%) of the true value. At most 976 buckets exist regardless of
+/// how many values are recorded.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    count: u64,
+    min: u64,
+    max: u64,
+    buckets: BTreeMap<usize, u64>,
+}
+
+#[cfg(feature = "std")]
+const LATENCY_SUB_BUCKET_BITS: u32 = 4;
+#[cfg(feature = "std")]
+const LATENCY_SUB_BUCKETS: u64 = 1 << LATENCY_SUB_BUCKET_BITS;
+
+#[cfg(feature = "std")]
+impl LatencyHistogram {
+    /// Record a single latency
+    pub fn record(&mut self, value_ns: u64) {
+        if self.count == 0 {
+            self.min = value_ns;
+            self.max = value_ns;
+        } else {
+            self.min = self.min.min(value_ns);
+            self.max = self.max.max(value_ns);
+        }
+        self.count += 1;
+        *self.buckets.entry(Self::bucket_index(value_ns)).or_insert(0) += 1;
+    }
+
+    /// Number of recorded values
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Value at percentile `p` (clamped to 0-100), or `None` when empty
+    pub fn percentile(&self, p: f64) -> Option<u64> {
+        if self.count == 0 {
+            return None;
+        }
+        let p = if p.is_nan() { 0.0 } else { p.clamp(0.0, 100.0) };
+        let rank = ((p / 100.0) * self.count as f64).ceil().max(1.0) as u64;
+
+        let mut seen = 0;
+        for (&index, &count) in &self.buckets {
+            seen += count;
+            if seen >= rank {
+                return Some(Self::bucket_upper_bound(index).clamp(self.min, self.max));
+            }
+        }
+        Some(self.max)
+    }
+
+    fn bucket_index(value: u64) -> usize {
+        if value < LATENCY_SUB_BUCKETS {
+            return value as usize;
+        }
+        let exp = 63 - value.leading_zeros();
+        let sub = (value >> (exp - LATENCY_SUB_BUCKET_BITS)) & (LATENCY_SUB_BUCKETS - 1);
+        (LATENCY_SUB_BUCKETS * u64::from(exp - LATENCY_SUB_BUCKET_BITS + 1) + sub) as usize
+    }
+
+    fn bucket_upper_bound(index: usize) -> u64 {
+        let index = index as u64;
+        if index < LATENCY_SUB_BUCKETS {
+            return index;
+        }
+        let shift = index / LATENCY_SUB_BUCKETS - 1;
+        let sub = index % LATENCY_SUB_BUCKETS;
+        let low = (LATENCY_SUB_BUCKETS + sub) << shift;
+        low + ((1u64 << shift) - 1)
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ActionCounts {
+    pub allow: u64,
+    pub deny: u64,
+    pub redact: u64,
+    pub escalate: u64,
+}
+
+// ============================================================================
+// PII Patterns
+// ============================================================================
+
+/// A source of PII matches
+///
+/// Implement this to plug non-regex detection (an NER model, checksum-based
+/// IDs, ...) into a [`Tork`] instance via [`Tork::add_detector`].
+#[cfg(feature = "std")]
+pub trait Detector {
+    /// Find all PII in `text`, with byte offsets into `text`
+    fn detect(&self, text: &str) -> Vec<PIIMatch>;
+
+    /// Lazily yield the matches of [`detect`](Detector::detect), ordered by
+    /// start offset
+    ///
+    /// The default collects `detect` and sorts it; override it when matches
+    /// can be produced incrementally.
+    fn detect_iter<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = PIIMatch> + 'a> {
+        let mut matches = self.detect(text);
+        matches.sort_by_key(|m| m.start_index);
+        Box::new(matches.into_iter())
+    }
+
+    /// Longest possible match in bytes, or `None` if unknown or unbounded
+    ///
+    /// Sizes the buffer of a [`StreamGovernor`]. The default is `None`.
+    fn max_match_len(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Detector backed by a single regular expression
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct RegexDetector {
+    pii_type: PIIType,
+    regex: Regex,
+    confidence: f32,
+    validator: Option<fn(&str) -> bool>,
+    /// Checked in place of `\b` for built-ins compiled under a
+    /// non-default [`BoundaryMode`]
+    boundary: Option<BoundaryMode>,
+    /// Built-in check against the surrounding text and today's date
+    context: Option<core_detect::ContextCheck>,
+    /// Source of today's date for `context`, the system clock if `None`
+    clock: Option<Arc<dyn Clock>>,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Debug for RegexDetector {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RegexDetector")
+            .field("pii_type", &self.pii_type)
+            .field("regex", &self.regex)
+            .field("confidence", &self.confidence)
+            .field("boundary", &self.boundary)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+impl RegexDetector {
+    /// Create a detector reporting every match of `regex` as `pii_type`
+    pub fn new(pii_type: PIIType, regex: Regex) -> Self {
+        RegexDetector {
+            pii_type,
+            regex,
+            confidence: 1.0,
+            validator: None,
+            boundary: None,
+            context: None,
+            clock: None,
+        }
+    }
+
+    /// Set the confidence reported for matches (default: 1.0)
+    pub fn with_confidence(mut self, confidence: f32) -> Self {
+        self.confidence = confidence;
+        self
+    }
+
+    /// Only report matches for which `validator` returns true, e.g. a
+    /// checksum over the matched text
+    pub fn with_validator(mut self, validator: fn(&str) -> bool) -> Self {
+        self.validator = Some(validator);
+        self
+    }
+
+    /// The PII type this detector reports
+    pub fn pii_type(&self) -> PIIType {
+        self.pii_type
+    }
+
+    /// The underlying regular expression
+    pub fn regex(&self) -> &Regex {
+        &self.regex
+    }
+}
+
+#[cfg(feature = "std")]
+impl RegexDetector {
+    /// The first match at or after byte `at` of `text` and where to search
+    /// next, with the match `None` if the validator or boundary rejected it
+    fn match_at(&self, text: &str, at: usize, capture: bool) -> Option<(Option<PIIMatch>, usize)> {
+        let caps = if capture { Some(self.regex.captures_at(text, at)?) } else { None };
+        let whole = match &caps {
+            Some(caps) => caps.get(0).expect("group 0 is always present"),
+            None => self.regex.find_at(text, at)?,
+        };
+        let next = match text[whole.end()..].chars().next() {
+            Some(c) if whole.is_empty() => whole.end() + c.len_utf8(),
+            None if whole.is_empty() => text.len() + 1,
+            _ => whole.end(),
+        };
+        let valid = self.validator.is_none_or(|v| v(whole.as_str()))
+            && self
+                .boundary
+                .is_none_or(|mode| mode.borders(text, whole.start(), whole.end()))
+            && self
+                .context
+                .is_none_or(|check| check(text, whole.start(), whole.end(), Some(self.today())));
+        if !valid {
+            return Some((None, next));
+        }
+        let groups = caps.iter().flat_map(|caps| {
+            self.regex.capture_names().zip(caps.iter()).filter_map(|(name, group)| {
+                let (name, group) = (name?, group?);
+                Some(MatchGroup {
+                    name: name.to_string(),
+                    start: group.start() - whole.start(),
+                    end: group.end() - whole.start(),
+                })
+            })
+        });
+        let m = PIIMatch {
+            pii_type: self.pii_type,
+            value: whole.as_str().to_string(),
+            start_index: whole.start(),
+            end_index: whole.end(),
+            location: None,
+            confidence: self.confidence,
+            groups: groups.collect(),
+        };
+        Some((Some(m), next))
+    }
+
+    /// Today's date as `(year, month, day)` by `clock`
+    fn today(&self) -> (i32, u32, u32) {
+        use chrono::Datelike;
+        let today = self.clock.as_ref().map_or_else(Utc::now, |clock| clock.utc_now()).date_naive();
+        (today.year(), today.month(), today.day())
+    }
+}
+
+/// Lazy matches of a [`RegexDetector`], held by reference or, for
+/// [`Tork::matches_iter`], through an `Arc` so it outlives a reload
+#[cfg(feature = "std")]
+struct RegexMatches<'a, D> {
+    detector: D,
+    text: &'a str,
+    at: usize,
+    /// Capturing is slower than finding, so only pay for it when there are
+    /// named groups to report
+    capture: bool,
+}
+
+#[cfg(feature = "std")]
+impl<'a, D: std::ops::Deref<Target = RegexDetector>> RegexMatches<'a, D> {
+    fn new(detector: D, text: &'a str) -> Self {
+        let capture = detector.regex.capture_names().any(|name| name.is_some());
+        RegexMatches {
+            detector,
+            text,
+            at: 0,
+            capture,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<D: std::ops::Deref<Target = RegexDetector>> Iterator for RegexMatches<'_, D> {
+    type Item = PIIMatch;
+
+    fn next(&mut self) -> Option<PIIMatch> {
+        while self.at <= self.text.len() {
+            let Some((m, next)) = self.detector.match_at(self.text, self.at, self.capture) else {
+                self.at = self.text.len() + 1;
+                return None;
+            };
+            self.at = next;
+            if m.is_some() {
+                return m;
+            }
+        }
+        None
+    }
+}
+
+#[cfg(feature = "std")]
+impl Detector for RegexDetector {
+    fn detect(&self, text: &str) -> Vec<PIIMatch> {
+        self.detect_iter(text).collect()
+    }
+
+    fn detect_iter<'a>(&'a self, text: &'a str) -> Box<dyn Iterator<Item = PIIMatch> + 'a> {
+        Box::new(RegexMatches::new(self, text))
+    }
+
+    fn max_match_len(&self) -> Option<usize> {
+        regex_syntax::parse(self.regex.as_str())
+            .ok()
+            .and_then(|hir| hir.properties().maximum_len())
+    }
+}
+
+/// Validator for built-in `CryptoWallet` matches: Bitcoin addresses must
+/// carry their base58check or bech32 checksum, which hex tokens that fit the
+/// pattern don't
+///
+/// Ethereum addresses are only checked under [`TorkConfig::validate_checksums`].
+#[cfg(feature = "std")]
+fn wallet_address_valid(value: &str) -> bool {
+    core_detect::wallet_shaped(value) && (value.starts_with("0x") || wallet::checksum_valid(value))
+}
+
+/// Whether `m` passes the check applied under [`TorkConfig::validate_checksums`]
+#[cfg(feature = "std")]
+fn checksum_valid(m: &PIIMatch) -> bool {
+    match m.pii_type {
+        PIIType::BankAccount => !is_trivial_digit_run(&m.value),
+        PIIType::Vin => vin_check_digit_valid(&m.value),
+        PIIType::CryptoWallet => wallet::checksum_valid(&m.value),
+        _ => true,
+    }
+}
+
+/// Whether the 9th character of a 17-character VIN is its check digit, or
+/// the VIN isn't North American and so needn't carry one
+#[cfg(feature = "std")]
+fn vin_check_digit_valid(value: &str) -> bool {
+    if !value.starts_with(['1', '2', '3', '4', '5']) {
+        return true;
+    }
+    value.chars().count() == 17 && value.chars().nth(8) == vin_check_digit(value)
+}
+
+/// Check digit of a 17-character VIN, or `None` if it has other characters
+///
+/// Letters transliterate to digits (`A`=1 ... `Z`=9, skipping I, O, Q), each
+/// position is weighted, and the weighted sum mod 11 is the check digit,
+/// with 10 written `X`.
+#[cfg(feature = "std")]
+fn vin_check_digit(value: &str) -> Option<char> {
+    const WEIGHTS: [u32; 17] = [8, 7, 6, 5, 4, 3, 2, 10, 0, 9, 8, 7, 6, 5, 4, 3, 2];
+    let transliterate = |c: char| match c {
+        '0'..='9' => c.to_digit(10),
+        'A' | 'J' => Some(1),
+        'B' | 'K' | 'S' => Some(2),
+        'C' | 'L' | 'T' => Some(3),
+        'D' | 'M' | 'U' => Some(4),
+        'E' | 'N' | 'V' => Some(5),
+        'F' | 'W' => Some(6),
+        'G' | 'P' | 'X' => Some(7),
+        'H' | 'Y' => Some(8),
+        'R' | 'Z' => Some(9),
+        _ => None,
+    };
+    let mut sum = 0;
+    for (c, weight) in value.chars().zip(WEIGHTS) {
+        sum += transliterate(c)? * weight;
+    }
+    Some(match sum % 11 {
+        10 => 'X',
+        d => char::from_digit(d, 10).expect("remainder below 10"),
+    })
+}
+
+/// Whether `value`'s digits are all the same or step by one throughout,
+/// e.g. `11111111`, `12345678` or `98765432`
+#[cfg(feature = "std")]
+fn is_trivial_digit_run(value: &str) -> bool {
+    let digits: Vec<i32> = value.chars().filter_map(|c| c.to_digit(10)).map(|d| d as i32).collect();
+    let steps: HashSet<i32> = digits.windows(2).map(|w| w[1] - w[0]).collect();
+    steps.len() == 1 && steps.iter().all(|step| step.abs() <= 1)
+}
+
+/// Built-in detectors per [`BoundaryMode`], compiled on first use and shared
+/// by every instance
+#[cfg(feature = "std")]
+static BUILTIN_PATTERNS: [OnceLock<Vec<RegexDetector>>; 3] = [const { OnceLock::new() }; 3];
+
+/// Built-in detectors, in priority order
+///
+/// The patterns are compiled once per process and each [`Tork`] clones this
+/// set, which only copies reference-counted handles. Call it at startup to
+/// keep compilation out of the first request (or out of a benchmark).
+#[cfg(feature = "std")]
+pub fn builtin_patterns() -> &'static [RegexDetector] {
+    try_builtin_patterns(BoundaryMode::Default).expect("built-in patterns compile")
+}
+
+/// Source of the first built-in pattern for `pii_type`, for tools that
+/// highlight the same spans Tork detects
+///
+/// `None` for types found by other means (names, postal codes, encoded PII)
+/// and for `Custom`. Cards have one pattern per grouping; see
+/// [`all_patterns`] for all of them. Validators such as the Luhn check are
+/// not part of the source, so a pattern can match text the detector rejects.
+#[cfg(feature = "std")]
+pub fn pattern_for(pii_type: PIIType) -> Option<&'static str> {
+    builtin_patterns()
+        .iter()
+        .find(|p| p.pii_type == pii_type)
+        .map(|p| p.regex.as_str())
+}
+
+/// Sources of every built-in pattern with its type, in priority order
+#[cfg(feature = "std")]
+pub fn all_patterns() -> Vec<(PIIType, &'static str)> {
+    builtin_patterns().iter().map(|p| (p.pii_type, p.regex.as_str())).collect()
+}
+
+/// Built-in detectors for `mode`, reporting a broken pattern instead of panicking
+#[cfg(feature = "std")]
+fn try_builtin_patterns(mode: BoundaryMode) -> Result<&'static [RegexDetector], Error> {
+    let cell = &BUILTIN_PATTERNS[mode as usize];
+    if let Some(patterns) = cell.get() {
+        return Ok(patterns);
+    }
+    let compiled = compile_pii_patterns(mode)?;
+    Ok(cell.get_or_init(|| compiled))
+}
+
+/// Detector for email addresses with bracketed `at`/`dot` substitutions,
+/// see [`TorkConfig::detect_obfuscated`]
+#[cfg(feature = "std")]
+fn obfuscated_email_detector() -> RegexDetector {
+    static SHARED: OnceLock<RegexDetector> = OnceLock::new();
+    SHARED
+        .get_or_init(|| {
+            let at = r"(?:\s*[\[({<]\s*(?i:at)\s*[\])}>]\s*|@)";
+            let dot = r"(?:\s*[\[({<]\s*(?i:dot)\s*[\])}>]\s*|\.)";
+            let pattern = format!(
+                r"\b(?P<local>[A-Za-z0-9._%+-]+){at}(?P<domain>[A-Za-z0-9-]+(?:{dot}[A-Za-z0-9-]+)*{dot}[A-Za-z]{{2,24}})\b"
+            );
+            RegexDetector {
+                pii_type: PIIType::Email,
+                regex: Regex::new(&pattern).expect("obfuscated email pattern compiles"),
+                confidence: 1.0,
+                // Plain addresses are left to the built-in pattern
+                validator: Some(|value| value.contains(['[', '(', '{', '<'])),
+                boundary: None,
+                context: None,
+                clock: None,
+            }
+        })
+        .clone()
+}
+
+/// Compile the built-in detectors, reporting a broken pattern by its type name
+///
+/// Outside [`BoundaryMode::Default`], the `\b` bordering each pattern but
+/// `Address` is dropped and the detector checks `mode` instead.
+#[cfg(feature = "std")]
+fn compile_pii_patterns(mode: BoundaryMode) -> Result<Vec<RegexDetector>, Error> {
+    let bordered = |pii_type: PIIType| mode != BoundaryMode::Default && pii_type != PIIType::Address;
+    let regex = |pii_type: PIIType, pattern: &str| {
+        let pattern = if bordered(pii_type) {
+            pattern
+                .strip_prefix(r"\b")
+                .and_then(|p| p.strip_suffix(r"\b"))
+                .expect("built-in pattern bordered by \\b")
+        } else {
+            pattern
+        };
+        Regex::new(pattern).map_err(|e| pattern_error(pii_type.as_str(), e))
+    };
+    let mut patterns = core_detect::BUILTIN_PATTERNS
+        .iter()
+        .map(|p| {
+            Ok(RegexDetector {
+                pii_type: p.pii_type,
+                regex: regex(p.pii_type, p.pattern)?,
+                confidence: p.confidence,
+                validator: match p.pii_type {
+                    PIIType::CryptoWallet => Some(wallet_address_valid),
+                    _ => p.validator,
+                },
+                boundary: None,
+                context: p.context,
+                clock: None,
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+    for pattern in patterns.iter_mut().filter(|p| bordered(p.pii_type)) {
+        pattern.boundary = Some(mode);
+    }
+    Ok(patterns)
+}
+
+// ============================================================================
+// Clock
+// ============================================================================
+
+/// Time source for processing-time measurement and receipt timestamps
+#[cfg(feature = "std")]
+pub trait Clock: Send + Sync {
+    /// Monotonic instant used to measure `processing_time_ns`
+    fn now(&self) -> Instant;
+    /// Wall-clock time stamped on receipts
+    fn utc_now(&self) -> DateTime<Utc>;
+}
+
+/// Clock backed by the system's monotonic and wall clocks
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Manually driven clock for deterministic tests
+///
+/// Time only moves when [`MockClock::advance`] is called, or by the
+/// configured step each time [`Clock::now`] is read.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct MockClock {
+    base: Instant,
+    utc_base: DateTime<Utc>,
+    elapsed_ns: AtomicU64,
+    step_ns: u64,
+}
+
+#[cfg(feature = "std")]
+impl MockClock {
+    /// Create a clock frozen at `utc_base`
+    pub fn new(utc_base: DateTime<Utc>) -> Self {
+        MockClock {
+            base: Instant::now(),
+            utc_base,
+            elapsed_ns: AtomicU64::new(0),
+            step_ns: 0,
+        }
+    }
+
+    /// Advance automatically by `step` every time `now()` is read
+    pub fn with_step(mut self, step: Duration) -> Self {
+        self.step_ns = step.as_nanos() as u64;
+        self
+    }
+
+    /// Move the clock forward
+    pub fn advance(&self, by: Duration) {
+        self.elapsed_ns.fetch_add(by.as_nanos() as u64, Ordering::SeqCst);
+    }
+
+    fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_ns.load(Ordering::SeqCst))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Clock for MockClock {
+    fn now(&self) -> Instant {
+        let elapsed = self.elapsed_ns.fetch_add(self.step_ns, Ordering::SeqCst);
+        self.base + Duration::from_nanos(elapsed)
+    }
+
+    fn utc_now(&self) -> DateTime<Utc> {
+        self.utc_base + self.elapsed()
+    }
+}
+
+// ============================================================================
+// Utility Functions
+// ============================================================================
+
+/// Generate SHA256 hash of text with prefix
+#[cfg(feature = "std")]
+pub fn hash_text(text: &str) -> String {
+    hash_text_with(HashAlgo::Sha256, text)
+}
+
+/// Hash text with `algo`, prefixed with the algorithm name (`sha512:...`)
+#[cfg(feature = "std")]
+pub fn hash_text_with(algo: HashAlgo, text: &str) -> String {
+    let digest = match algo {
+        HashAlgo::Sha256 => hex::encode(Sha256::digest(text.as_bytes())),
+        HashAlgo::Sha512 => hex::encode(Sha512::digest(text.as_bytes())),
+        HashAlgo::Blake3 => blake3::hash(text.as_bytes()).to_hex().to_string(),
+    };
+    format!("{}:{}", algo.prefix(), digest)
+}
+
+/// Keyed hash of text with `algo`, for [`TorkConfig::hash_salt`]
+///
+/// SHA-2 algorithms use HMAC (`hmac-sha256:...`); BLAKE3 uses its keyed mode
+/// with a key derived from `key` (`blake3-keyed:...`). Without the key, the
+/// hash of a short, known-format value can't be recovered by enumeration.
+#[cfg(feature = "std")]
+pub fn hash_text_keyed(algo: HashAlgo, key: &[u8], text: &str) -> String {
+    fn hmac_hex<M: Mac + KeyInit>(key: &[u8], text: &str) -> String {
+        let mut mac = <M as KeyInit>::new_from_slice(key).expect("HMAC accepts keys of any length");
+        mac.update(text.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    match algo {
+        HashAlgo::Sha256 => format!("hmac-sha256:{}", hmac_hex::<Hmac<Sha256>>(key, text)),
+        HashAlgo::Sha512 => format!("hmac-sha512:{}", hmac_hex::<Hmac<Sha512>>(key, text)),
+        HashAlgo::Blake3 => {
+            let key = blake3::derive_key("tork-governance 2026 receipt hash salt", key);
+            format!("blake3-keyed:{}", blake3::keyed_hash(&key, text.as_bytes()).to_hex())
+        }
+    }
+}
+
+/// Generate a unique receipt ID
+#[cfg(feature = "std")]
+pub fn generate_receipt_id() -> String {
+    format!("rcpt_{}", Uuid::new_v4().to_string().replace("-", ""))
+}
+
+/// Generate a receipt ID derived from content, for [`ReceiptIdStrategy::ContentDerived`]
+#[cfg(feature = "std")]
+pub fn generate_content_receipt_id(
+    input_hash: &str,
+    policy_version: &str,
+    time_bucket: i64,
+) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(input_hash.as_bytes());
+    hasher.update([0]);
+    hasher.update(policy_version.as_bytes());
+    hasher.update([0]);
+    hasher.update(time_bucket.to_be_bytes());
+    format!("rcpt_{}", &hex::encode(hasher.finalize())[..32])
+}
+
+// ============================================================================
+// PII Detection
+// ============================================================================
+
+/// Default-config instance behind the free detection functions
+///
+/// Reusing it keeps each regex's match cache warm across calls.
+#[cfg(feature = "std")]
+fn default_tork() -> &'static Tork {
+    static DEFAULT: OnceLock<Tork> = OnceLock::new();
+    DEFAULT.get_or_init(Tork::new)
+}
+
+/// Detect PII in text and return detection results with redacted text
+#[cfg(feature = "std")]
+pub fn detect_pii(text: &str) -> PIIDetectionResult {
+    default_tork().detect(text)
+}
+
+/// Detect PII, borrowing `text` as the redacted output when nothing matched
+///
+/// See [`Tork::detect_cow`].
+#[cfg(feature = "std")]
+pub fn detect_pii_cow(text: &str) -> (PIIScanResult, Cow<'_, str>) {
+    default_tork().detect_cow(text)
+}
+
+/// Assemble a detection result from matches listed in detector priority order
+///
+/// Priority order decides overlaps during redaction; the result lists the
+/// matches in text order.
+#[cfg(feature = "std")]
+fn build_detection_result(
+    text: &str,
+    mut matches: Vec<PIIMatch>,
+    redactor: &mut Redactor,
+) -> PIIDetectionResult {
+    let (redacted_text, spans) = redact_matches(text, &matches, redactor);
+    matches.sort_by_key(|m| (m.start_index, m.end_index));
+    detection_result_with(matches, redacted_text.into_owned(), spans)
+}
+
+/// Summarize matches without their values or offsets
+#[cfg(feature = "std")]
+fn scan_result(matches: &[PIIMatch]) -> PIIScanResult {
+    let types: HashSet<PIIType> = matches.iter().map(|m| m.pii_type).collect();
+    PIIScanResult {
+        has_pii: !matches.is_empty(),
+        count: matches.len(),
+        types: types.into_iter().collect(),
+    }
+}
+
+/// Assemble a detection result from matches and an already-redacted text
+#[cfg(feature = "std")]
+fn detection_result_with(
+    matches: Vec<PIIMatch>,
+    redacted_text: String,
+    spans: Vec<RedactionSpan>,
+) -> PIIDetectionResult {
+    let detected_types: HashSet<PIIType> = matches.iter().map(|m| m.pii_type).collect();
+
+    PIIDetectionResult {
+        has_pii: !matches.is_empty(),
+        types: detected_types.into_iter().collect(),
+        count: matches.len(),
+        matches,
+        redacted_text,
+        spans,
+        truncated: false,
+    }
+}
+
+/// Byte ranges of the invalid sequences in `input`, one per U+FFFD that
+/// lossy decoding substitutes
+#[cfg(feature = "std")]
+fn invalid_utf8_ranges(input: &[u8]) -> Vec<Range<usize>> {
+    let mut ranges = Vec::new();
+    let mut pos = 0;
+    for chunk in input.utf8_chunks() {
+        pos += chunk.valid().len();
+        let invalid = chunk.invalid().len();
+        if invalid > 0 {
+            ranges.push(pos..pos + invalid);
+        }
+        pos += invalid;
+    }
+    ranges
+}
+
+/// Drop matches that lose an overlap, see [`TorkConfig::outranks`]
+///
+/// The account rule is a bare digit run, so it also fires on unformatted phone
+/// numbers and on digit groups of hyphenated IDs. Runs touching a hyphen are
+/// treated as part of a larger token, and a span claimed by another type
+/// wins unless `numeric_precedence` ranks the account above it.
+#[cfg(feature = "std")]
+fn resolve_overlaps(text: &str, matches: Vec<PIIMatch>, config: &TorkConfig) -> Vec<PIIMatch> {
+    core_detect::resolve_overlaps(text, matches, &config.numeric_precedence)
+}
+
+/// Whether `m` is a `BankAccount` run touching a hyphen
+#[cfg(feature = "std")]
+fn is_hyphenated_account(text: &str, m: &PIIMatch) -> bool {
+    core_detect::is_hyphenated_account(text, m.pii_type, m.start_index, m.end_index)
+}
+
+/// Renders placeholders for a single governance call
+#[cfg(feature = "std")]
+struct Redactor {
+    style: RedactionStyle,
+    placeholders: HashMap<PIIType, String>,
+    /// Policy version appended to placeholders under `mark_redactions`
+    mark: Option<String>,
+    indices: HashMap<(PIIType, String), usize>,
+    next_index: HashMap<PIIType, usize>,
+    /// Seeded from `synthetic_seed`, or randomly on first use
+    rng: Option<synthetic::SyntheticRng>,
+    fakes: HashMap<(PIIType, String), String>,
+}
+
+#[cfg(feature = "std")]
+impl Redactor {
+    fn new(config: &TorkConfig) -> Self {
+        Redactor {
+            style: config.redaction_style.clone(),
+            placeholders: config.placeholders.clone(),
+            mark: config.mark_redactions.then(|| config.policy_version.clone()),
+            indices: HashMap::new(),
+            next_index: HashMap::new(),
+            rng: config.synthetic_seed.map(synthetic::SyntheticRng::new),
+            fakes: HashMap::new(),
+        }
+    }
+
+    fn placeholder(&mut self, m: &PIIMatch) -> String {
+        let base = self
+            .placeholders
+            .get(&m.pii_type)
+            .map_or(m.pii_type.redaction(), String::as_str);
+        let mark = self.mark.as_deref();
+        match &self.style {
+            RedactionStyle::Placeholder => mark_placeholder(base, mark),
+            RedactionStyle::GroupMask { keep } => mask_groups(m, &mark_placeholder(base, mark), keep),
+            RedactionStyle::FixedWidth { width, fill } => fill.to_string().repeat(*width),
+            RedactionStyle::Sentence => mark_placeholder(REDACTED_SENTENCE, mark),
+            RedactionStyle::PreserveLength => {
+                let len = m.value.chars().count();
+                let base_len = base.chars().count();
+                if len < base_len {
+                    "*".repeat(len)
+                } else {
+                    format!("{}{}", base, "*".repeat(len - base_len))
+                }
+            }
+            RedactionStyle::Equilength => {
+                let len = m.value.len();
+                let mut cut = base.len().min(len);
+                while !base.is_char_boundary(cut) {
+                    cut -= 1;
+                }
+                format!("{}{}", &base[..cut], "*".repeat(len - cut))
+            }
+            RedactionStyle::Indexed => {
+                let next_index = &mut self.next_index;
+                let index = *self
+                    .indices
+                    .entry((m.pii_type, m.value.clone()))
+                    .or_insert_with(|| {
+                        let next = next_index.entry(m.pii_type).or_insert(0);
+                        *next += 1;
+                        *next
+                    });
+                let indexed = match base.strip_suffix(']') {
+                    Some(open) => format!("{}:{}]", open, index),
+                    None => format!("{}:{}", base, index),
+                };
+                mark_placeholder(&indexed, mark)
+            }
+            RedactionStyle::Synthetic => {
+                let rng = self
+                    .rng
+                    .get_or_insert_with(|| synthetic::SyntheticRng::new(Uuid::new_v4().as_u64_pair().0));
+                self.fakes
+                    .entry((m.pii_type, m.value.clone()))
+                    .or_insert_with(|| synthetic::synthesize(m, rng).unwrap_or_else(|| base.to_string()))
+                    .clone()
+            }
+        }
+    }
+}
+
+/// Add `#mark` to a placeholder, inside its closing bracket if it has one
+#[cfg(feature = "std")]
+fn mark_placeholder(placeholder: &str, mark: Option<&str>) -> String {
+    match (mark, placeholder.strip_suffix(']')) {
+        (None, _) => placeholder.to_string(),
+        (Some(mark), Some(open)) => format!("{}#{}]", open, mark),
+        (Some(mark), None) => format!("{}#{}", placeholder, mark),
+    }
+}
+
+/// Render `m` with the groups named in `keep` left in place, see
+/// [`RedactionStyle::GroupMask`]
+#[cfg(feature = "std")]
+fn mask_groups(m: &PIIMatch, placeholder: &str, keep: &[String]) -> String {
+    if !m.groups.iter().any(|g| keep.contains(&g.name)) {
+        return placeholder.to_string();
+    }
+
+    let mut out = String::with_capacity(m.value.len());
+    let mut last = 0;
+    // Start of the masked run in progress and the end of its last group
+    let mut masked: Option<(usize, usize)> = None;
+    for group in &m.groups {
+        if keep.contains(&group.name) {
+            if let Some((start, end)) = masked.take() {
+                out.push_str(&m.value[last..start]);
+                out.push_str(placeholder);
+                last = end;
+            }
+            out.push_str(&m.value[last..group.end]);
+            last = group.end;
+        } else {
+            masked = Some(match masked {
+                Some((start, _)) => (start, group.end),
+                None => (group.start, group.end),
+            });
+        }
+    }
+    if let Some((start, end)) = masked {
+        out.push_str(&m.value[last..start]);
+        out.push_str(placeholder);
+        last = end;
+    }
+    out.push_str(&m.value[last..]);
+    out
+}
+
+/// Byte range of the sentence containing `start..end`, see
+/// [`RedactionStyle::Sentence`]
+///
+/// The range starts after any leading whitespace and includes the closing
+/// punctuation but not a closing newline.
+#[cfg(feature = "std")]
+fn sentence_bounds(text: &str, start: usize, end: usize) -> (usize, usize) {
+    let bytes = text.as_bytes();
+    let ends_sentence = |i: usize| match bytes[i] {
+        b'\n' => true,
+        b'.' | b'!' | b'?' => bytes.get(i + 1).is_none_or(|b| b.is_ascii_whitespace()),
+        _ => false,
+    };
+
+    let mut sentence_start = (0..start).rev().find(|&i| ends_sentence(i)).map_or(0, |i| i + 1);
+    while sentence_start < start && bytes[sentence_start].is_ascii_whitespace() {
+        sentence_start += 1;
+    }
+    let sentence_end = (end..bytes.len())
+        .find(|&i| ends_sentence(i))
+        .map_or(bytes.len(), |i| if bytes[i] == b'\n' { i } else { i + 1 });
+    (sentence_start, sentence_end)
+}
+
+/// Replace matched spans with their placeholders
+///
+/// Matches are claimed in the order given, so when two overlap the one from
+/// the earlier detector wins and the later one is left unredacted. Returns the
+/// redacted text, borrowed when nothing was replaced, and the substitutions
+/// made, in text order.
+#[cfg(feature = "std")]
+fn redact_matches<'a>(
+    text: &'a str,
+    matches: &[PIIMatch],
+    redactor: &mut Redactor,
+) -> (Cow<'a, str>, Vec<RedactionSpan>) {
+    let claimed = core_detect::claim(matches);
+
+    // Each replaced range with the match that produced it
+    let mut ranges: Vec<(usize, usize, &PIIMatch)> = Vec::with_capacity(claimed.len());
+    for m in claimed {
+        let (start, end) = match redactor.style {
+            RedactionStyle::Sentence => sentence_bounds(text, m.start_index, m.end_index),
+            _ => (m.start_index, m.end_index),
+        };
+        match ranges.last_mut() {
+            Some(last) if start < last.1 => last.1 = last.1.max(end),
+            _ => ranges.push((start, end, m)),
+        }
+    }
+    if ranges.is_empty() {
+        return (Cow::Borrowed(text), Vec::new());
+    }
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut spans = Vec::with_capacity(ranges.len());
+    let mut last = 0;
+    for (start, end, m) in ranges {
+        let replacement = redactor.placeholder(m);
+        redacted.push_str(&text[last..start]);
+        redacted.push_str(&replacement);
+        spans.push(RedactionSpan {
+            original_start: start,
+            original_end: end,
+            original_value: text[start..end].to_string(),
+            replacement,
+        });
+        last = end;
+    }
+    redacted.push_str(&text[last..]);
+    (Cow::Owned(redacted), spans)
+}
+
+// ============================================================================
+// Tork Struct
+// ============================================================================
+
+/// Boxed detector as held by [`Tork`]
+#[cfg(feature = "std")]
+pub type BoxedDetector = Box<dyn Detector + Send + Sync>;
+
+/// Maximum compiled size, in bytes, of a user-supplied pattern
+///
+/// The regex engine runs in linear time, so the risk from untrusted patterns
+/// is memory and compile time rather than backtracking. Patterns whose
+/// compiled program or lazy DFA cache would exceed this are rejected.
+#[cfg(feature = "std")]
+pub const CUSTOM_PATTERN_SIZE_LIMIT: usize = 1 << 20;
+
+/// Compile configured custom patterns into detectors, enforcing size limits
+#[cfg(feature = "std")]
+fn compile_custom_patterns(patterns: &[CustomPattern]) -> Result<Vec<RegexDetector>, Error> {
+    patterns
+        .iter()
+        .map(|p| {
+            RegexBuilder::new(&p.pattern)
+                .size_limit(CUSTOM_PATTERN_SIZE_LIMIT)
+                .dfa_size_limit(CUSTOM_PATTERN_SIZE_LIMIT)
+                .build()
+                .map(|regex| RegexDetector::new(p.pii_type, regex))
+                .map_err(|e| pattern_error(&p.name, e))
+        })
+        .collect()
+}
+
+/// Compile allowlist patterns into one set, enforcing the custom pattern
+/// size limits
+#[cfg(feature = "std")]
+fn compile_allowlist_patterns<I, S>(patterns: I) -> Result<RegexSet, regex::Error>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    RegexSetBuilder::new(patterns)
+        .size_limit(CUSTOM_PATTERN_SIZE_LIMIT)
+        .dfa_size_limit(CUSTOM_PATTERN_SIZE_LIMIT)
+        .build()
+}
+
+/// [`TorkConfig::allowlist_patterns`] compiled into one set
+///
+/// Patterns that fail to compile are left out; [`TorkConfig::validate`]
+/// reports them.
+#[cfg(feature = "std")]
+fn allowlist_set(patterns: &[String]) -> RegexSet {
+    let valid = patterns.iter().filter(|p| compile_allowlist_patterns([p]).is_ok());
+    compile_allowlist_patterns(valid).unwrap_or_else(|_| RegexSet::empty())
+}
+
+/// Map a regex compile failure for the pattern called `name` to an [`Error`]
+#[cfg(feature = "std")]
+fn pattern_error(name: &str, e: regex::Error) -> Error {
+    match e {
+        regex::Error::CompiledTooBig(limit) => Error::PatternTooLarge {
+            name: name.to_string(),
+            limit,
+        },
+        other => Error::InvalidPattern {
+            name: name.to_string(),
+            message: other.to_string(),
+        },
+    }
+}
+
+/// Built-in detectors followed by the config's name detector and custom
+/// patterns, in priority order, with birth dates checked against `clock`
+/// (default: the system clock)
+///
+/// Fails on the first custom pattern that doesn't compile, enabled or not,
+/// so a broken pattern never lets its PII through unnoticed.
+#[cfg(feature = "std")]
+fn config_detectors(config: &TorkConfig, clock: Option<&Arc<dyn Clock>>) -> Result<Vec<CompiledDetector>, Error> {
+    let mut builtins = try_builtin_patterns(config.boundary_mode)?.to_vec();
+    for builtin in builtins.iter_mut().filter(|p| p.context.is_some()) {
+        builtin.clock = clock.cloned();
+    }
+    let enabled = |t: &PIIType| config.enabled_types.contains(t);
+    let custom = compile_custom_patterns(&config.custom_patterns)?;
+    let names = enabled(&PIIType::Name).then(|| CompiledDetector::Other(Box::new(NameDetector::new())));
+    let postal = enabled(&PIIType::PostalCode).then(|| CompiledDetector::Other(Box::new(PostalCodeDetector::new())));
+    let obfuscated = (config.detect_obfuscated && enabled(&PIIType::Email)).then(obfuscated_email_detector);
+    Ok(builtins
+        .into_iter()
+        .chain(obfuscated)
+        .filter(|p| enabled(&p.pii_type))
+        .map(|p| CompiledDetector::Regex(Arc::new(p)))
+        .chain(names)
+        .chain(postal)
+        .chain(
+            custom
+                .into_iter()
+                .filter(|p| enabled(&p.pii_type))
+                .map(|p| CompiledDetector::Regex(Arc::new(p))),
+        )
+        .collect())
+}
+
+/// A config and the detectors and allowlist built from it, swapped as one
+/// by [`Tork::reload_config`]
+#[cfg(feature = "std")]
+struct Compiled {
+    config: Arc<TorkConfig>,
+    /// Shared so [`Tork::set_policy`] can keep them without recompiling
+    detectors: Arc<[CompiledDetector]>,
+    /// `config.allowlist_patterns`, compiled once rather than per match
+    allowlist: RegexSet,
+}
+
+#[cfg(feature = "std")]
+impl Compiled {
+    fn new(config: TorkConfig, detectors: Vec<CompiledDetector>) -> Arc<Self> {
+        Self::with_detectors(Arc::new(config), detectors.into())
+    }
+
+    fn with_detectors(config: Arc<TorkConfig>, detectors: Arc<[CompiledDetector]>) -> Arc<Self> {
+        Arc::new(Compiled {
+            allowlist: allowlist_set(&config.allowlist_patterns),
+            config,
+            detectors,
+        })
+    }
+
+    /// These detectors filtered by `config`'s allowlist patterns, for a
+    /// call governed under a config other than this one
+    fn for_config(self: Arc<Self>, config: &TorkConfig) -> Arc<Self> {
+        if config.allowlist_patterns == self.config.allowlist_patterns {
+            return self;
+        }
+        Arc::new(Compiled {
+            config: Arc::clone(&self.config),
+            detectors: Arc::clone(&self.detectors),
+            allowlist: allowlist_set(&config.allowlist_patterns),
+        })
+    }
+}
+
+/// A detector built from config
+///
+/// Regex detectors are shared rather than boxed so that
+/// [`Tork::matches_iter`] can search them lazily without borrowing a policy
+/// that a reload may drop.
+#[cfg(feature = "std")]
+enum CompiledDetector {
+    Regex(Arc<RegexDetector>),
+    Other(BoxedDetector),
+}
+
+#[cfg(feature = "std")]
+impl CompiledDetector {
+    fn detector(&self) -> &dyn Detector {
+        match self {
+            CompiledDetector::Regex(d) => d.as_ref(),
+            CompiledDetector::Other(d) => d.as_ref(),
+        }
+    }
+
+    /// [`Detector::detect_iter`] without borrowing `self`; only regex
+    /// matches are produced lazily
+    fn detect_iter_owned<'a>(&self, text: &'a str) -> Box<dyn Iterator<Item = PIIMatch> + 'a> {
+        match self {
+            CompiledDetector::Regex(d) => Box::new(RegexMatches::new(Arc::clone(d), text)),
+            CompiledDetector::Other(d) => Box::new(d.detect_iter(text).collect::<Vec<_>>().into_iter()),
+        }
+    }
+}
+
+/// Matches from one detection pass over a text
+#[cfg(feature = "std")]
+struct Collected {
+    /// Matches the config accepts, in detector priority order
+    matches: Vec<PIIMatch>,
+    /// Whether detection stopped early at `max_matches`
+    truncated: bool,
+    /// Matches too unsure to redact that no accepted or surer match
+    /// overlaps, see [`TorkConfig::uncertain_action`]
+    uncertain: Vec<PIIMatch>,
+    /// Whether detectors were skipped because [`Stop::deadline`] passed
+    timed_out: bool,
+}
+
+/// When a detection pass stops early
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Default)]
+struct Stop<'a> {
+    /// Give up with `None` once set, checked before each detector and after
+    /// each match it reports
+    cancel: Option<&'a AtomicBool>,
+    /// Skip the remaining detectors once reached, checked before each one
+    deadline: Option<Instant>,
+}
+
+#[cfg(feature = "std")]
+impl Stop<'_> {
+    fn cancelled(&self) -> bool {
+        self.cancel.is_some_and(|flag| flag.load(Ordering::Relaxed))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Collected {
+    /// Type of the first uncertain match
+    fn uncertain_type(&self) -> Option<PIIType> {
+        self.uncertain.first().map(|m| m.pii_type)
+    }
+}
+
+/// What [`Tork::govern_json`] and [`Tork::govern_url`] gather from the
+/// strings they scan
+#[cfg(feature = "std")]
+#[derive(Default)]
+struct LeafScan {
+    matches: Vec<PIIMatch>,
+    truncated: bool,
+    uncertain: Option<PIIType>,
+}
+
+/// Destination for the NDJSON audit log, see [`Tork::with_audit_sink`]
+#[cfg(feature = "std")]
+pub type AuditSink = Box<dyn Write + Send>;
+
+/// One audit log line: the receipt plus detected types, never matched values
+#[cfg(feature = "std")]
+#[derive(Serialize)]
+struct AuditEntry<'a> {
+    #[serde(flatten)]
+    receipt: &'a GovernanceReceipt,
+    pii_types: Vec<PIIType>,
+}
+
+/// Lazy, start-ordered merge of every detector's matches, see [`Tork::matches_iter`]
+#[cfg(feature = "std")]
+struct MatchesIter<'a> {
+    text: &'a str,
+    compiled: Arc<Compiled>,
+    sources: Vec<Box<dyn Iterator<Item = PIIMatch> + 'a>>,
+    /// Next accepted match of each source
+    heads: Vec<Option<PIIMatch>>,
+    /// `(start_index, source)` of every pending head; ties go to the earlier detector
+    heap: BinaryHeap<Reverse<(usize, usize)>>,
+    /// Merged matches pulled ahead while deciding whether a match survives
+    lookahead: VecDeque<PIIMatch>,
+    /// `(end_index, type)` of merged matches that may still overlap the next
+    recent: Vec<(usize, PIIType)>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> MatchesIter<'a> {
+    fn new(
+        text: &'a str,
+        compiled: Arc<Compiled>,
+        sources: Vec<Box<dyn Iterator<Item = PIIMatch> + 'a>>,
+    ) -> Self {
+        let mut iter = MatchesIter {
+            text,
+            compiled,
+            heads: (0..sources.len()).map(|_| None).collect(),
+            sources,
+            heap: BinaryHeap::new(),
+            lookahead: VecDeque::new(),
+            recent: Vec::new(),
+        };
+        for source in 0..iter.sources.len() {
+            iter.advance(source);
+        }
+        iter
+    }
+
+    /// Load the next accepted match of `source` into its head slot
+    fn advance(&mut self, source: usize) {
+        let compiled = Arc::clone(&self.compiled);
+        let next = self.sources[source]
+            .by_ref()
+            .find(|m| compiled.config.accepts(m, &compiled.allowlist));
+        if let Some(m) = &next {
+            self.heap.push(Reverse((m.start_index, source)));
+        }
+        self.heads[source] = next;
+    }
+
+    /// Next match across all sources, ordered by start offset
+    fn next_merged(&mut self) -> Option<PIIMatch> {
+        if let Some(m) = self.lookahead.pop_front() {
+            return Some(m);
+        }
+        let Reverse((_, source)) = self.heap.pop()?;
+        let m = self.heads[source].take();
+        self.advance(source);
+        m
+    }
+
+    /// Whether `m` survives overlap resolution, as in [`resolve_overlaps`]
+    fn survives(&mut self, m: &PIIMatch) -> bool {
+        if is_hyphenated_account(self.text, m) {
+            return false;
+        }
+        let config = Arc::clone(&self.compiled.config);
+        if !config.can_be_outranked(m.pii_type) {
+            return true;
+        }
+        if self
+            .recent
+            .iter()
+            .any(|&(end, t)| end > m.start_index && config.outranks(t, m.pii_type))
+        {
+            return false;
+        }
+        // Pull every match starting inside this span to see if one outranks it
+        while self.lookahead.back().is_none_or(|b| b.start_index < m.end_index) {
+            let Reverse((_, source)) = match self.heap.pop() {
+                Some(entry) => entry,
+                None => break,
+            };
+            let next = self.heads[source].take().expect("heap entries have heads");
+            self.advance(source);
+            self.lookahead.push_back(next);
+        }
+        !self
+            .lookahead
+            .iter()
+            .any(|x| x.start_index < m.end_index && config.outranks(x.pii_type, m.pii_type))
+    }
+}
+
+#[cfg(feature = "std")]
+impl Iterator for MatchesIter<'_> {
+    type Item = PIIMatch;
+
+    fn next(&mut self) -> Option<PIIMatch> {
+        loop {
+            let m = self.next_merged()?;
+            let survives = self.survives(&m);
+            // Losers still outrank later matches, as in the batch resolver
+            self.recent.retain(|&(end, _)| end > m.start_index);
+            self.recent.push((m.end_index, m.pii_type));
+            if survives {
+                return Some(m);
+            }
+        }
+    }
 }
 
-/// Generate a unique receipt ID
-pub fn generate_receipt_id() -> String {
-    format!("rcpt_{}", Uuid::new_v4().to_string().replace("-", ""))
+/// Persistable snapshot of a [`Tork`] instance, see [`Tork::export_state`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TorkState {
+    /// Configuration, including custom patterns. `hash_salt` is not
+    /// serialized and must be set again after loading.
+    pub config: TorkConfig,
+    pub stats: TorkStats,
+    /// Chain hash of the last receipt issued, linked from the next one
+    pub last_receipt_hash: Option<String>,
 }
 
-// ============================================================================
-// PII Detection
-// ============================================================================
+/// Policies keyed by their `policy_version`, see [`Tork::govern_with_policy`]
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, Default)]
+pub struct PolicyRegistry {
+    policies: HashMap<String, TorkConfig>,
+}
 
-/// Detect PII in text and return detection results with redacted text
-pub fn detect_pii(text: &str) -> PIIDetectionResult {
-    let patterns = get_pii_patterns();
-    let mut matches: Vec<PIIMatch> = Vec::new();
-    let mut detected_types: HashSet<PIIType> = HashSet::new();
-    let mut redacted_text = text.to_string();
-
-    for pattern in &patterns {
-        for mat in pattern.regex.find_iter(text) {
-            detected_types.insert(pattern.pii_type);
-            matches.push(PIIMatch {
-                pii_type: pattern.pii_type,
-                value: mat.as_str().to_string(),
-                start_index: mat.start(),
-                end_index: mat.end(),
-            });
-        }
+#[cfg(feature = "std")]
+impl PolicyRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
 
-        // Redact this pattern type
-        redacted_text = pattern
-            .regex
-            .replace_all(&redacted_text, pattern.pii_type.redaction())
-            .to_string();
+    /// Add `config` under its `policy_version`, returning any policy it replaces
+    pub fn register(&mut self, config: TorkConfig) -> Option<TorkConfig> {
+        self.policies.insert(config.policy_version.clone(), config)
     }
 
-    PIIDetectionResult {
-        has_pii: !matches.is_empty(),
-        types: detected_types.into_iter().collect(),
-        count: matches.len(),
-        matches,
-        redacted_text,
+    /// Policy registered for `version`
+    pub fn get(&self, version: &str) -> Option<&TorkConfig> {
+        self.policies.get(version)
+    }
+
+    /// Registered versions, in no particular order
+    pub fn versions(&self) -> impl Iterator<Item = &str> {
+        self.policies.keys().map(String::as_str)
     }
 }
 
-// ============================================================================
-// Tork Struct
-// ============================================================================
+/// Text with an example of each common kind of PII, run by [`Tork::warm_up`]
+#[cfg(feature = "std")]
+const WARM_UP_SAMPLE: &str = "Jane Doe, jane.doe@example.com, +1 (555) 123-4567, SSN 123-45-6789, \
+    card 4111 1111 1111 1111, IP 192.168.1.1 and 2001:db8::1, IBAN GB82 WEST 1234 5698 7654 32, \
+    born 1990-01-31, VIN 1HGCM82633A004352, acct 12345678, MTIzLTQ1LTY3ODk=, https://example.com";
 
 /// Main Tork governance struct
+#[cfg(feature = "std")]
 pub struct Tork {
-    config: TorkConfig,
-    stats: TorkStats,
-    patterns: Vec<PIIPattern>,
+    /// Config and the built-in and config-declared detectors. Each call
+    /// clones the `Arc` and works from that snapshot, so the lock is only
+    /// held to read or swap the pointer.
+    compiled: RwLock<Arc<Compiled>>,
+    /// Behind a lock so governance can run through `&self` from many threads
+    stats: Mutex<TorkStats>,
+    /// Detectors registered through `add_detector`, shared with clones
+    custom_detectors: Vec<Arc<dyn Detector + Send + Sync>>,
+    clock: Arc<dyn Clock>,
+    /// Shared with clones, which append to the same log
+    audit_sink: Option<Arc<Mutex<AuditSink>>>,
+    /// Chain hash of the last receipt issued, held while the next is built
+    last_receipt_hash: Mutex<Option<String>>,
+    /// Registered policies by version, each with its own detectors
+    policies: HashMap<String, Arc<Compiled>>,
 }
 
+#[cfg(feature = "std")]
 impl Tork {
     /// Create a new Tork instance with default configuration
     pub fn new() -> Self {
-        Tork {
-            config: TorkConfig::default(),
-            stats: TorkStats::default(),
-            patterns: get_pii_patterns(),
-        }
+        Self::with_config(TorkConfig::default())
+    }
+
+    /// Create a new Tork instance, reporting a built-in pattern that fails
+    /// to compile instead of panicking
+    pub fn try_new() -> Result<Self, Error> {
+        Self::try_with_config(TorkConfig::default())
     }
 
     /// Create a new Tork instance with custom configuration
+    ///
+    /// # Panics
+    ///
+    /// If a custom pattern doesn't compile, rather than governing
+    /// without it; use [`try_with_config`](Tork::try_with_config) to have it
+    /// reported.
     pub fn with_config(config: TorkConfig) -> Self {
+        let detectors = config_detectors(&config, None).unwrap_or_else(|e| panic!("invalid config: {}", e));
+        Self::with_detectors(detectors, config)
+    }
+
+    /// Create a new Tork instance, failing if a built-in or custom pattern
+    /// doesn't compile or the config doesn't [`validate`](TorkConfig::validate)
+    pub fn try_with_config(config: TorkConfig) -> Result<Self, Error> {
+        config.validate()?;
+        let detectors = config_detectors(&config, None)?;
+        Ok(Self::with_detectors(detectors, config))
+    }
+
+    fn with_detectors(detectors: Vec<CompiledDetector>, config: TorkConfig) -> Self {
         Tork {
-            config,
-            stats: TorkStats::default(),
-            patterns: get_pii_patterns(),
+            compiled: RwLock::new(Compiled::new(config, detectors)),
+            stats: Mutex::new(TorkStats::default()),
+            custom_detectors: Vec::new(),
+            clock: Arc::new(SystemClock),
+            audit_sink: None,
+            last_receipt_hash: Mutex::new(None),
+            policies: HashMap::new(),
         }
     }
 
+    /// Create a Tork instance that appends every receipt to `sink` as NDJSON
+    ///
+    /// Each governance call writes one JSON object per line holding the
+    /// receipt fields and the detected `pii_types`; matched values are never
+    /// written. Writes are best-effort: failures are counted in
+    /// [`TorkStats::audit_write_errors`] instead of failing the call.
+    pub fn with_audit_sink(config: TorkConfig, sink: AuditSink) -> Self {
+        let mut tork = Self::with_config(config);
+        tork.audit_sink = Some(Arc::new(Mutex::new(sink)));
+        tork
+    }
+
+    /// Create a new Tork instance from a policy file
+    ///
+    /// Files ending in `.json` are parsed as JSON, anything else as TOML.
+    pub fn from_policy_file(path: &Path) -> Result<Self, Error> {
+        let contents = std::fs::read_to_string(path)?;
+        let config = match path.extension().and_then(|e| e.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => TorkConfig::from_json_str(&contents)?,
+            _ => TorkConfig::from_toml_str(&contents)?,
+        };
+        Ok(Self::with_config(config))
+    }
+
     /// Apply governance with regional and industry-specific detection
-    pub fn govern_with_options(&mut self, input: &str, options: GovernOptions) -> GovernanceResult {
-        let mut result = self.govern(input);
+    pub fn govern_with_options(&self, input: &str, options: GovernOptions) -> GovernanceResult {
+        let ctx = GovernContext {
+            session_context: options.session_context,
+            ..Default::default()
+        };
+        let mut result = self.govern_with_context(input, ctx);
         result.region = options.region;
         result.industry = options.industry;
-        if options.session_context.is_some() {
-            result.receipt.session_context = options.session_context.clone();
-            result.session_context = options.session_context;
-        }
         result
     }
 
+    /// Apply governance, recording `ctx`'s identifiers in the receipt
+    ///
+    /// The receipt holds the identifiers before it is chained and written to
+    /// the audit sink, so both carry them.
+    pub fn govern_with_context(&self, input: &str, ctx: GovernContext) -> GovernanceResult {
+        let compiled = self.compiled();
+        self.govern_in(input, &compiled, &compiled.config, &ctx)
+    }
+
     /// Apply governance to input text
-    pub fn govern(&mut self, input: &str) -> GovernanceResult {
-        let start_time = Instant::now();
-
-        // Detect PII
-        let pii = self.detect_pii_internal(input);
-
-        // Determine action
-        let (action, output) = if pii.has_pii {
-            let action = self.config.default_action;
-            let output = match action {
-                GovernanceAction::Redact => pii.redacted_text.clone(),
-                _ => input.to_string(),
-            };
-            (action, output)
+    ///
+    /// The config and detectors are read once, so a concurrent
+    /// [`reload_config`](Tork::reload_config) applies either wholly or not
+    /// at all to this call.
+    pub fn govern(&self, input: &str) -> GovernanceResult {
+        let compiled = self.compiled();
+        self.govern_in(input, &compiled, &compiled.config, &GovernContext::default())
+    }
+
+    /// Apply governance to input text, failing with [`GovernanceDenied`]
+    /// when the action is `Deny`
+    ///
+    /// `Allow`, `Redact` and `Escalate` return `Ok`, so a handler can
+    /// short-circuit on denial with `?`.
+    pub fn try_govern(&self, input: &str) -> Result<GovernanceResult, GovernanceDenied> {
+        let result = self.govern(input);
+        match result.action {
+            GovernanceAction::Deny => Err(GovernanceDenied {
+                result: Box::new(result),
+            }),
+            _ => Ok(result),
+        }
+    }
+
+    /// Apply governance under `config` for this call only
+    ///
+    /// Actions, thresholds, allowlist, denylist and receipt settings come from
+    /// `config`, while detection uses this instance's compiled detectors, so
+    /// `config.custom_patterns` is not compiled here and types the instance
+    /// has disabled stay disabled. Stats are recorded on this instance.
+    pub fn govern_with(&self, input: &str, config: &TorkConfig) -> GovernanceResult {
+        let compiled = self.compiled().for_config(config);
+        self.govern_in(input, &compiled, config, &GovernContext::default())
+    }
+
+    /// Govern under `config` with the detectors of `compiled`, recording
+    /// `ctx` in the receipt
+    fn govern_in(
+        &self,
+        input: &str,
+        compiled: &Compiled,
+        config: &TorkConfig,
+        ctx: &GovernContext,
+    ) -> GovernanceResult {
+        #[cfg(feature = "tracing")]
+        let span = telemetry::govern_span();
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let start_time = self.clock.now();
+        let collected = self.collect_matches(compiled, input, config);
+        let uncertain = collected.uncertain_type();
+        let result = self.govern_matches(
+            input,
+            collected.matches,
+            collected.truncated,
+            uncertain,
+            start_time,
+            config,
+            ctx,
+        );
+        self.record_stats([&result], result.receipt.processing_time_ns);
+
+        #[cfg(feature = "tracing")]
+        telemetry::record_governance(&span, &result);
+
+        result
+    }
+
+    /// Apply governance unless `cancel` is set before it finishes, returning
+    /// `None` if it was
+    ///
+    /// The flag is checked before each detector runs and after each match a
+    /// detector reports, so a cancelled call stops within one regex search
+    /// rather than after the whole document; a detector that finds nothing
+    /// still scans to the end of the input before the flag is seen. Matches
+    /// below `min_confidence` for [`TorkConfig::uncertain_action`] come from
+    /// the same pass, so no uncancellable rescan follows it. The flag is
+    /// checked once more before redaction. A cancelled call issues no
+    /// receipt and isn't counted in stats.
+    pub fn govern_cancellable(&self, input: &str, cancel: &AtomicBool) -> Option<GovernanceResult> {
+        #[cfg(feature = "tracing")]
+        let span = telemetry::govern_span();
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let start_time = self.clock.now();
+        let compiled = &*self.compiled();
+        let config = &compiled.config;
+        let stop = Stop {
+            cancel: Some(cancel),
+            ..Default::default()
+        };
+        let collected = self.collect_matches_until(compiled, input, config, stop)?;
+        if cancel.load(Ordering::Relaxed) {
+            return None;
+        }
+        let uncertain = collected.uncertain_type();
+        let result = self.govern_matches(
+            input,
+            collected.matches,
+            collected.truncated,
+            uncertain,
+            start_time,
+            config,
+            &GovernContext::default(),
+        );
+        self.record_stats([&result], result.receipt.processing_time_ns);
+
+        #[cfg(feature = "tracing")]
+        telemetry::record_governance(&span, &result);
+
+        Some(result)
+    }
+
+    /// Apply governance to each line of `input`, returning 1-based line
+    /// numbers with their results
+    ///
+    /// Lines are split on `\n` only and keep any `\r`, so joining the
+    /// outputs with `\n` gives the governed text. Every line gets its own
+    /// action and receipt, while stats count the whole input as one call
+    /// under the most severe action of its lines.
+    pub fn govern_lines(&self, input: &str) -> Vec<(usize, GovernanceResult)> {
+        let start_time = self.clock.now();
+        let compiled = &*self.compiled();
+        let config = &compiled.config;
+        let results: Vec<(usize, GovernanceResult)> = input
+            .split('\n')
+            .enumerate()
+            .map(|(i, line)| {
+                #[cfg(feature = "tracing")]
+                let span = telemetry::govern_span();
+                #[cfg(feature = "tracing")]
+                let _entered = span.enter();
+
+                let line_start = self.clock.now();
+                let collected = self.collect_matches(compiled, line, config);
+                let uncertain = collected.uncertain_type();
+                let result = self.govern_matches(
+                    line,
+                    collected.matches,
+                    collected.truncated,
+                    uncertain,
+                    line_start,
+                    config,
+                    &GovernContext::default(),
+                );
+
+                #[cfg(feature = "tracing")]
+                telemetry::record_governance(&span, &result);
+
+                (i + 1, result)
+            })
+            .collect();
+        let processing_time_ns = self.clock.now().duration_since(start_time).as_nanos() as u64;
+        self.record_stats(results.iter().map(|(_, result)| result), processing_time_ns);
+        results
+    }
+
+    /// Apply governance to raw bytes, decoding them per [`TorkConfig::utf8_mode`]
+    ///
+    /// Valid UTF-8 is governed in place without copying. Under `Lossy`, each
+    /// invalid sequence is replaced with U+FFFD and its byte range in `input`
+    /// listed in [`GovernanceResult::invalid_utf8`]; match offsets, `output`
+    /// and receipt hashes then refer to the decoded text. Under `Strict`,
+    /// invalid input returns [`Error::InvalidUtf8`] and is not governed.
+    pub fn govern_bytes(&self, input: &[u8]) -> Result<GovernanceResult, Error> {
+        match std::str::from_utf8(input) {
+            Ok(text) => Ok(self.govern(text)),
+            Err(e) => match self.get_config().utf8_mode {
+                Utf8Mode::Strict => Err(Error::InvalidUtf8 {
+                    position: e.valid_up_to(),
+                }),
+                Utf8Mode::Lossy => {
+                    let mut result = self.govern(&String::from_utf8_lossy(input));
+                    result.invalid_utf8 = invalid_utf8_ranges(input);
+                    Ok(result)
+                }
+            },
+        }
+    }
+
+    /// Apply governance under the registered policy for `policy_version`
+    ///
+    /// Detection follows the policy's own enabled types and custom patterns,
+    /// and the receipt records its `policy_version`. Returns
+    /// [`Error::UnknownPolicy`] if no policy is registered for the version.
+    pub fn govern_with_policy(
+        &self,
+        input: &str,
+        policy_version: &str,
+    ) -> Result<GovernanceResult, Error> {
+        let compiled = self
+            .policies
+            .get(policy_version)
+            .ok_or_else(|| Error::UnknownPolicy {
+                version: policy_version.to_string(),
+            })?;
+        Ok(self.govern_in(input, compiled, &compiled.config, &GovernContext::default()))
+    }
+
+    /// Govern `input` with an action chosen by the caller
+    ///
+    /// `pii` is normally the result of [`detect`](Tork::detect) on the same
+    /// input. Config actions and the denylist are not consulted; the output,
+    /// receipt, audit entry and stats follow `action` exactly as if the
+    /// policy had chosen it.
+    pub fn apply_action(
+        &self,
+        input: &str,
+        pii: &PIIDetectionResult,
+        action: GovernanceAction,
+    ) -> GovernanceResult {
+        let start_time = self.clock.now();
+        let mut rationale: Vec<String> = PIIType::all()
+            .iter()
+            .filter(|t| pii.types.contains(t))
+            .map(|t| format!("{} matched and caller_action={}", t.as_str(), action.as_str()))
+            .collect();
+        if rationale.is_empty() {
+            rationale.push(format!("no PII detected and caller_action={}", action.as_str()));
+        }
+        self.issue_result(input, pii.clone(), start_time, &self.get_config(), action, rationale)
+    }
+
+    /// Apply governance to a transcript of messages as one document
+    ///
+    /// Each message is scanned and redacted on its own, so match offsets are
+    /// relative to their message, whose index is recorded in `location` as a
+    /// JSON pointer (`/0`, `/1`, ...). The result aggregates all matches
+    /// under one action and receipt and counts as a single call in stats;
+    /// its `output` is the governed messages joined with `\n`.
+    ///
+    /// The returned messages follow the action: redacted for `Redact` and
+    /// `Escalate`, unchanged for `Allow`, and for `Deny` whatever
+    /// `deny_output` makes of each one.
+    pub fn govern_messages(&self, msgs: &[&str]) -> (Vec<String>, GovernanceResult) {
+        #[cfg(feature = "tracing")]
+        let span = telemetry::govern_span();
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let start_time = self.clock.now();
+        let compiled = self.compiled();
+        let mut scan = LeafScan::default();
+        let mut redactor = Redactor::new(&compiled.config);
+        let redacted: Vec<String> = msgs
+            .iter()
+            .enumerate()
+            .map(|(i, msg)| {
+                self.redact_leaf(&compiled, msg, &format!("/{}", i), &mut scan, &mut redactor)
+                    .unwrap_or_else(|| msg.to_string())
+            })
+            .collect();
+
+        let input = msgs.join("\n");
+        let mut pii = detection_result_with(scan.matches, redacted.join("\n"), Vec::new());
+        pii.truncated = scan.truncated;
+        let result = self.finish_governance(&input, pii, scan.uncertain, start_time, &compiled.config);
+
+        let governed = match result.action {
+            GovernanceAction::Redact | GovernanceAction::Escalate => redacted,
+            GovernanceAction::Deny => msgs
+                .iter()
+                .zip(&redacted)
+                .map(|(msg, redacted)| compiled.config.deny_output.apply(msg, redacted))
+                .collect(),
+            GovernanceAction::Allow => msgs.iter().map(|msg| msg.to_string()).collect(),
+        };
+
+        #[cfg(feature = "tracing")]
+        telemetry::record_governance(&span, &result);
+
+        (governed, result)
+    }
+
+    /// Apply governance to every string leaf of a JSON document
+    ///
+    /// Each string is scanned and replaced with its redacted form; numbers,
+    /// booleans, nulls and object keys are left intact. The result aggregates
+    /// all matches, each carrying the JSON pointer of its string in
+    /// `location`. `output` holds the serialized governed document.
+    ///
+    /// The returned value follows the action: the redacted document for
+    /// `Redact` and `Escalate`, the original for `Allow`, and for `Deny` whatever
+    /// `deny_output` selects (`Empty` yields `null`).
+    pub fn govern_json(
+        &mut self,
+        value: &serde_json::Value,
+    ) -> (serde_json::Value, GovernanceResult) {
+        #[cfg(feature = "tracing")]
+        let span = telemetry::govern_span();
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let start_time = self.clock.now();
+        let compiled = self.compiled();
+        let mut redacted = value.clone();
+        let mut scan = LeafScan::default();
+        let mut redactor = Redactor::new(&compiled.config);
+        self.redact_json_leaves(&compiled, &mut redacted, String::new(), &mut scan, &mut redactor);
+
+        let input = value.to_string();
+        let mut pii = detection_result_with(scan.matches, redacted.to_string(), Vec::new());
+        pii.truncated = scan.truncated;
+        let result = self.finish_governance(&input, pii, scan.uncertain, start_time, &compiled.config);
+
+        let governed = match result.action {
+            GovernanceAction::Redact | GovernanceAction::Escalate => redacted,
+            GovernanceAction::Deny => match &compiled.config.deny_output {
+                DenyOutput::Passthrough => value.clone(),
+                DenyOutput::Empty => serde_json::Value::Null,
+                DenyOutput::RedactedAnyway => redacted,
+                DenyOutput::Message(message) => serde_json::Value::String(message.clone()),
+            },
+            _ => value.clone(),
+        };
+
+        #[cfg(feature = "tracing")]
+        telemetry::record_governance(&span, &result);
+
+        (governed, result)
+    }
+
+    /// Apply governance to the path segments and query values of a URL
+    ///
+    /// Each path segment and query value is decoded, scanned and, if it holds
+    /// PII, replaced with its redacted form re-encoded; scheme, host, query
+    /// keys, the fragment and untouched components are kept as parsed. A
+    /// query entry without `=` is scanned as a value. Matches carry
+    /// `path/<index>`, `query/<key>` or, for an entry without `=`, `query`
+    /// in `location`, with offsets into the decoded component.
+    ///
+    /// `output` follows the action as for [`govern`](Tork::govern), with the
+    /// redacted URL standing in for the redacted text. Returns
+    /// [`Error::InvalidUrl`] if `url` doesn't parse as an absolute URL.
+    pub fn govern_url(&self, url: &str) -> Result<GovernanceResult, Error> {
+        let parsed = url::Url::parse(url).map_err(|e| Error::InvalidUrl { message: e.to_string() })?;
+
+        #[cfg(feature = "tracing")]
+        let span = telemetry::govern_span();
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let start_time = self.clock.now();
+        let compiled = self.compiled();
+        let mut scan = LeafScan::default();
+        let mut redactor = Redactor::new(&compiled.config);
+        let redacted = urls::redact_components(parsed, |text, location| {
+            self.redact_leaf(&compiled, text, location, &mut scan, &mut redactor)
+        });
+
+        let mut pii = detection_result_with(scan.matches, redacted, Vec::new());
+        pii.truncated = scan.truncated;
+        let result = self.finish_governance(url, pii, scan.uncertain, start_time, &compiled.config);
+
+        #[cfg(feature = "tracing")]
+        telemetry::record_governance(&span, &result);
+
+        Ok(result)
+    }
+
+    /// Apply governance to Markdown or HTML, redacting only within text
+    ///
+    /// Tags, attributes, link destinations and other markup are left intact,
+    /// so a redacted link keeps its brackets and target. Match offsets and
+    /// redaction spans refer to the full input.
+    pub fn govern_markup(&self, input: &str, markup: Markup) -> GovernanceResult {
+        #[cfg(feature = "tracing")]
+        let span = telemetry::govern_span();
+        #[cfg(feature = "tracing")]
+        let _entered = span.enter();
+
+        let start_time = self.clock.now();
+        let compiled = &*self.compiled();
+        let config = &compiled.config;
+        let mut matches = Vec::new();
+        let mut truncated = false;
+        let mut uncertain = None;
+        for range in markup::text_ranges(input, markup) {
+            let text = &input[range.clone()];
+            let found = self.collect_matches(compiled, text, config);
+            truncated |= found.truncated;
+            uncertain = uncertain.or(found.uncertain_type());
+            matches.extend(found.matches.into_iter().map(|mut m| {
+                m.start_index += range.start;
+                m.end_index += range.start;
+                m
+            }));
+        }
+        let result = self.govern_matches(
+            input,
+            matches,
+            truncated,
+            uncertain,
+            start_time,
+            config,
+            &GovernContext::default(),
+        );
+        self.record_stats([&result], result.receipt.processing_time_ns);
+
+        #[cfg(feature = "tracing")]
+        telemetry::record_governance(&span, &result);
+
+        result
+    }
+
+    /// Decide the action for matches found in `input`, then redact (unless
+    /// [`TorkConfig::receipt_only`] makes that unnecessary) and build the
+    /// result, leaving stats to the caller
+    #[allow(clippy::too_many_arguments)]
+    fn govern_matches(
+        &self,
+        input: &str,
+        mut matches: Vec<PIIMatch>,
+        truncated: bool,
+        uncertain: Option<PIIType>,
+        start_time: Instant,
+        config: &TorkConfig,
+        ctx: &GovernContext,
+    ) -> GovernanceResult {
+        let over_budget = config.exceeds_redaction_budget(input, &matches);
+        let (action, rationale, deny_reason) =
+            config.decide(input, &scan_result(&matches).types, uncertain, over_budget);
+        let mut pii = if config.receipt_only && !config.outputs_redacted(action) {
+            matches.sort_by_key(|m| (m.start_index, m.end_index));
+            detection_result_with(matches, String::new(), Vec::new())
         } else {
-            (GovernanceAction::Allow, input.to_string())
+            build_detection_result(input, matches, &mut Redactor::new(config))
+        };
+        pii.truncated = truncated;
+        let mut result = self.build_result(input, pii, start_time, config, action, rationale, ctx);
+        result.budget_exceeded = over_budget;
+        result.deny_reason = deny_reason;
+        result
+    }
+
+    /// Redact string leaves in place, collecting matches tagged with their JSON pointer
+    fn redact_json_leaves(
+        &self,
+        compiled: &Compiled,
+        value: &mut serde_json::Value,
+        pointer: String,
+        scan: &mut LeafScan,
+        redactor: &mut Redactor,
+    ) {
+        match value {
+            serde_json::Value::String(s) => {
+                if let Some(redacted) = self.redact_leaf(compiled, s, &pointer, scan, redactor) {
+                    *s = redacted;
+                }
+            }
+            serde_json::Value::Array(items) => {
+                for (i, item) in items.iter_mut().enumerate() {
+                    let pointer = format!("{}/{}", pointer, i);
+                    self.redact_json_leaves(compiled, item, pointer, scan, redactor);
+                }
+            }
+            serde_json::Value::Object(map) => {
+                for (key, item) in map.iter_mut() {
+                    let escaped = key.replace('~', "~0").replace('/', "~1");
+                    let pointer = format!("{}/{}", pointer, escaped);
+                    self.redact_json_leaves(compiled, item, pointer, scan, redactor);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Redact one string of structured input, collecting its matches tagged
+    /// with `location`; `None` if it holds no PII
+    fn redact_leaf(
+        &self,
+        compiled: &Compiled,
+        text: &str,
+        location: &str,
+        scan: &mut LeafScan,
+        redactor: &mut Redactor,
+    ) -> Option<String> {
+        let collected = self.collect_matches(compiled, text, &compiled.config);
+        scan.uncertain = scan.uncertain.or(collected.uncertain_type());
+        scan.truncated |= collected.truncated;
+        if collected.matches.is_empty() {
+            return None;
+        }
+        let pii = build_detection_result(text, collected.matches, redactor);
+        scan.matches.extend(pii.matches.into_iter().map(|mut m| {
+            m.location = Some(location.to_string());
+            m
+        }));
+        Some(pii.redacted_text)
+    }
+
+    /// Resolve the action for detected PII, then build the receipt and update stats
+    fn finish_governance(
+        &self,
+        input: &str,
+        pii: PIIDetectionResult,
+        uncertain: Option<PIIType>,
+        start_time: Instant,
+        config: &TorkConfig,
+    ) -> GovernanceResult {
+        let over_budget = config.exceeds_redaction_budget(input, &pii.matches);
+        let (action, rationale, deny_reason) = config.decide(input, &pii.types, uncertain, over_budget);
+        let mut result = self.issue_result(input, pii, start_time, config, action, rationale);
+        result.budget_exceeded = over_budget;
+        result.deny_reason = deny_reason;
+        result
+    }
+
+    /// Build output, receipt, audit entry and stats for a decided action
+    fn issue_result(
+        &self,
+        input: &str,
+        pii: PIIDetectionResult,
+        start_time: Instant,
+        config: &TorkConfig,
+        action: GovernanceAction,
+        rationale: Vec<String>,
+    ) -> GovernanceResult {
+        let result = self.build_result(input, pii, start_time, config, action, rationale, &GovernContext::default());
+        self.record_stats([&result], result.receipt.processing_time_ns);
+        result
+    }
+
+    /// Build output, receipt and audit entry for a decided action, without
+    /// counting the call in stats
+    ///
+    /// The receipt carries `ctx` when it is chained and audited, both under
+    /// the chain lock so concurrent calls link up in the order they audit.
+    #[allow(clippy::too_many_arguments)]
+    fn build_result(
+        &self,
+        input: &str,
+        pii: PIIDetectionResult,
+        start_time: Instant,
+        config: &TorkConfig,
+        action: GovernanceAction,
+        rationale: Vec<String>,
+        ctx: &GovernContext,
+    ) -> GovernanceResult {
+        let output = match action {
+            GovernanceAction::Redact | GovernanceAction::Escalate => pii.redacted_text.clone(),
+            GovernanceAction::Deny => config.deny_output.apply(input, &pii.redacted_text),
+            GovernanceAction::Allow => input.to_string(),
         };
+        let redacted_output = config.outputs_redacted(action);
 
-        let processing_time_ns = start_time.elapsed().as_nanos() as u64;
+        let detected_at = self.clock.now();
+        let detection_time_ns = detected_at.duration_since(start_time).as_nanos() as u64;
 
         // Generate receipt
+        let timestamp = self.clock.utc_now();
+        let input_hash = config.receipt_hash(input);
+        let receipt_id = match config.receipt_id_strategy {
+            ReceiptIdStrategy::Random => generate_receipt_id(),
+            ReceiptIdStrategy::ContentDerived { window_secs } => {
+                let bucket = match window_secs {
+                    0 => 0,
+                    window => timestamp.timestamp().div_euclid(window as i64),
+                };
+                generate_content_receipt_id(&input_hash, &config.policy_version, bucket)
+            }
+        };
+        let output_hash = config.receipt_hash(&output);
+        let receipt_time_ns = self.clock.now().duration_since(detected_at).as_nanos() as u64;
+        let mut chain = self.lock_chain();
         let receipt = GovernanceReceipt {
-            receipt_id: generate_receipt_id(),
-            timestamp: Utc::now(),
-            input_hash: hash_text(input),
-            output_hash: hash_text(&output),
+            receipt_id,
+            timestamp,
+            input_hash,
+            output_hash,
             action,
-            policy_version: self.config.policy_version.clone(),
-            processing_time_ns,
-            session_context: None,
+            policy_version: config.policy_version.clone(),
+            processing_time_ns: detection_time_ns + receipt_time_ns,
+            detection_time_ns,
+            receipt_time_ns,
+            session_context: ctx.session_context.clone(),
+            escalated: action == GovernanceAction::Escalate,
+            prev_receipt_hash: chain.take(),
+            synthetic: redacted_output && pii.has_pii && config.redaction_style == RedactionStyle::Synthetic,
+            correlation_id: ctx.correlation_id.clone(),
+            tenant_id: ctx.tenant_id.clone(),
         };
+        *chain = Some(receipt.chain_hash());
+        let audited = self.write_audit(&receipt, &pii.types);
+        drop(chain);
 
-        // Update stats
-        self.stats.total_calls += 1;
-        if pii.has_pii {
-            self.stats.total_pii_detected += 1;
-        }
-        self.stats.total_processing_time_ns += processing_time_ns;
-        match action {
-            GovernanceAction::Allow => self.stats.action_counts.allow += 1,
-            GovernanceAction::Deny => self.stats.action_counts.deny += 1,
-            GovernanceAction::Redact => self.stats.action_counts.redact += 1,
-            GovernanceAction::Escalate => self.stats.action_counts.escalate += 1,
+        if !audited {
+            self.lock_stats().audit_write_errors += 1;
         }
 
+        let modified = output != input;
         GovernanceResult {
             action,
             output,
@@ -397,76 +3827,554 @@ impl Tork {
             receipt,
             region: None,
             industry: None,
-            session_context: None,
+            session_context: ctx.session_context.clone(),
+            rationale,
+            escalated: action == GovernanceAction::Escalate,
+            invalid_utf8: Vec::new(),
+            input_len: input.len(),
+            modified,
+            original: config.retain_input.then(|| input.to_string()),
+            budget_exceeded: false,
+            deny_reason: None,
         }
     }
 
-    /// Internal PII detection using cached patterns
-    fn detect_pii_internal(&self, text: &str) -> PIIDetectionResult {
-        let mut matches: Vec<PIIMatch> = Vec::new();
-        let mut detected_types: HashSet<PIIType> = HashSet::new();
-        let mut redacted_text = text.to_string();
+    /// Count `results` in stats as a single call taking `processing_time_ns`,
+    /// under the most severe of their actions
+    fn record_stats<'a>(&self, results: impl IntoIterator<Item = &'a GovernanceResult>, processing_time_ns: u64) {
+        let mut stats = self.lock_stats();
+        let mut has_pii = false;
+        let mut action = GovernanceAction::Allow;
+        for result in results {
+            has_pii |= result.pii.has_pii;
+            action = std::cmp::max_by_key(action, result.action, GovernanceAction::severity);
+            for m in &result.pii.matches {
+                *stats.type_counts.entry(m.pii_type).or_insert(0) += 1;
+            }
+        }
+        stats.total_calls += 1;
+        if has_pii {
+            stats.total_pii_detected += 1;
+        }
+        stats.total_processing_time_ns += processing_time_ns;
+        stats.latency.record(processing_time_ns);
+        match action {
+            GovernanceAction::Allow => stats.action_counts.allow += 1,
+            GovernanceAction::Deny => stats.action_counts.deny += 1,
+            GovernanceAction::Redact => stats.action_counts.redact += 1,
+            GovernanceAction::Escalate => stats.action_counts.escalate += 1,
+        }
+
+        #[cfg(feature = "metrics")]
+        self::metrics::record_call(has_pii, action, processing_time_ns);
+    }
 
-        for pattern in &self.patterns {
-            for mat in pattern.regex.find_iter(text) {
-                detected_types.insert(pattern.pii_type);
-                matches.push(PIIMatch {
-                    pii_type: pattern.pii_type,
-                    value: mat.as_str().to_string(),
-                    start_index: mat.start(),
-                    end_index: mat.end(),
-                });
+    /// Append a receipt to the audit sink, returning whether it was written
+    fn write_audit(&self, receipt: &GovernanceReceipt, types: &[PIIType]) -> bool {
+        let Some(sink) = &self.audit_sink else {
+            return true;
+        };
+        let entry = AuditEntry {
+            receipt,
+            pii_types: types.to_vec(),
+        };
+        let Ok(mut line) = serde_json::to_vec(&entry) else {
+            return false;
+        };
+        line.push(b'\n');
+        let mut sink = sink.lock().unwrap_or_else(PoisonError::into_inner);
+        sink.write_all(&line).and_then(|_| sink.flush()).is_ok()
+    }
+
+    /// Replace the time source (default: [`SystemClock`]), which also dates
+    /// the built-in birth-date checks
+    pub fn set_clock(&mut self, clock: Arc<dyn Clock>) {
+        self.clock = clock;
+        let config = TorkConfig::clone(&self.get_config());
+        self.replace_config(config);
+        let policies = std::mem::take(&mut self.policies);
+        self.policies = policies
+            .into_iter()
+            .map(|(version, compiled)| {
+                let config = TorkConfig::clone(&compiled.config);
+                let compiled = self.compile_policy(config).expect("registered policies compile");
+                (version, compiled)
+            })
+            .collect();
+    }
+
+    /// Replace the policies available to [`govern_with_policy`](Tork::govern_with_policy)
+    ///
+    /// Each policy is validated and its detectors compiled here. On error the
+    /// current registry stays in place.
+    pub fn set_policy_registry(&mut self, policies: PolicyRegistry) -> Result<(), Error> {
+        self.policies = policies
+            .policies
+            .into_iter()
+            .map(|(version, config)| {
+                config.validate()?;
+                Ok((version, self.compile_policy(config)?))
+            })
+            .collect::<Result<_, Error>>()?;
+        Ok(())
+    }
+
+    fn compile_policy(&self, config: TorkConfig) -> Result<Arc<Compiled>, Error> {
+        let detectors = config_detectors(&config, Some(&self.clock))?;
+        Ok(Compiled::new(config, detectors))
+    }
+
+    /// Register an additional detector, run after the built-in ones
+    ///
+    /// Where a custom match overlaps a built-in match, the built-in
+    /// placeholder is used in the redacted text.
+    pub fn add_detector(&mut self, detector: BoxedDetector) {
+        self.custom_detectors.push(Arc::from(detector));
+    }
+
+    /// Detect PII, stopping once `timeout` has elapsed
+    ///
+    /// The deadline is checked between detectors: a detector that has started
+    /// runs to completion, and any remaining detectors are skipped. The result
+    /// then covers only the detectors that ran and `timed_out` is set.
+    /// Otherwise it finds what [`detect`](Tork::detect) finds, including
+    /// the `scan_encoded` pass and skipping existing placeholders.
+    pub fn detect_with_timeout(&self, input: &str, timeout: Duration) -> TimedDetectionResult {
+        let stop = Stop {
+            deadline: self.clock.now().checked_add(timeout),
+            ..Default::default()
+        };
+        let compiled = &*self.compiled();
+        let config = &compiled.config;
+        let collected = self
+            .collect_matches_until(compiled, input, config, stop)
+            .expect("detection without a cancel flag runs to completion");
+        let mut pii = build_detection_result(input, collected.matches, &mut Redactor::new(config));
+        pii.truncated = collected.truncated;
+        TimedDetectionResult {
+            pii,
+            timed_out: collected.timed_out,
+        }
+    }
+
+    /// Run every detector once so the first real call doesn't pay for it
+    ///
+    /// Regexes build their matching automata lazily on first use, which makes
+    /// the first [`govern`](Tork::govern) noticeably slower than the rest.
+    /// Call this at startup, after [`add_detector`](Tork::add_detector) and
+    /// [`set_config`](Tork::set_config). Stats and the receipt chain are not
+    /// touched.
+    pub fn warm_up(&self) {
+        let _ = self.detect(WARM_UP_SAMPLE);
+    }
+
+    /// Count PII in `input` without building a redacted copy
+    ///
+    /// Reports the same `count` and `types` as a full detection, for
+    /// high-throughput scanning where the redacted output isn't needed.
+    pub fn scan(&self, input: &str) -> PIIScanResult {
+        let compiled = self.compiled();
+        let matches = self.collect_matches(&compiled, input, &compiled.config).matches;
+        scan_result(&matches)
+    }
+
+    /// Detect PII, returning a summary and the redacted text
+    ///
+    /// The redacted text borrows `text` when nothing was replaced, so clean
+    /// input is never copied. Use [`detect`](Tork::detect) when the matches
+    /// or redaction spans are needed.
+    pub fn detect_cow<'a>(&self, text: &'a str) -> (PIIScanResult, Cow<'a, str>) {
+        let compiled = &*self.compiled();
+        let config = &compiled.config;
+        let matches = self.collect_matches(compiled, text, config).matches;
+        let mut redactor = Redactor::new(config);
+        let (redacted, _) = redact_matches(text, &matches, &mut redactor);
+        (scan_result(&matches), redacted)
+    }
+
+    /// Detect PII without deciding an action
+    ///
+    /// Uses this instance's detectors and config filters (confidence,
+    /// enabled types, allowlist). No receipt is issued and stats are not
+    /// recorded; pass the result to [`apply_action`](Tork::apply_action)
+    /// once the application has chosen what to do.
+    pub fn detect(&self, text: &str) -> PIIDetectionResult {
+        let compiled = self.compiled();
+        let mut redactor = Redactor::new(&compiled.config);
+        self.detect_pii_with(&compiled, text, &compiled.config, &mut redactor)
+    }
+
+    /// Detect PII under `config`, numbering placeholders with a caller-held redactor
+    fn detect_pii_with(
+        &self,
+        compiled: &Compiled,
+        text: &str,
+        config: &TorkConfig,
+        redactor: &mut Redactor,
+    ) -> PIIDetectionResult {
+        let collected = self.collect_matches(compiled, text, config);
+        let mut pii = build_detection_result(text, collected.matches, redactor);
+        pii.truncated = collected.truncated;
+        pii
+    }
+
+    /// Lazily yield matches in `text`, ordered by start offset
+    ///
+    /// Matches stream from each detector as they are found and are merged
+    /// without collecting them all first, so callers can stop early on large
+    /// inputs. The same matches as [`detect_pii`] are produced; where two
+    /// start at the same offset, the earlier detector's comes first.
+    pub fn matches_iter<'a>(&'a self, text: &'a str) -> impl Iterator<Item = PIIMatch> + 'a {
+        let compiled = self.compiled();
+        let sources = compiled
+            .detectors
+            .iter()
+            .map(|d| d.detect_iter_owned(text))
+            .chain(self.custom_detectors.iter().map(|d| d.detect_iter(text)))
+            .collect();
+        MatchesIter::new(text, Arc::clone(&compiled), sources)
+    }
+
+    /// Start redacting a stream of chunks with this instance's detectors and config
+    pub fn stream(&self) -> StreamGovernor<'_> {
+        StreamGovernor::new(self)
+    }
+
+    /// Run every detector, keeping matches `config` accepts in detector priority order
+    ///
+    /// Encoded blobs found under `config.scan_encoded` come first and replace
+    /// any other match inside them, and matches overlapping a placeholder
+    /// from earlier redaction are dropped. Also collects whether detection
+    /// stopped early at `config.max_matches` and any uncertain matches.
+    fn collect_matches(&self, compiled: &Compiled, text: &str, config: &TorkConfig) -> Collected {
+        self.collect_matches_until(compiled, text, config, Stop::default())
+            .expect("detection without a cancel flag runs to completion")
+    }
+
+    /// [`collect_matches`](Tork::collect_matches), stopping early as `stop` says
+    fn collect_matches_until(
+        &self,
+        compiled: &Compiled,
+        text: &str,
+        config: &TorkConfig,
+        stop: Stop<'_>,
+    ) -> Option<Collected> {
+        match config.normalize_unicode.then(|| normalize::Normalized::new(text)).flatten() {
+            Some(normalized) => {
+                let mut collected = self.collect_matches_in(compiled, normalized.text(), config, stop)?;
+                collected.matches = collected
+                    .matches
+                    .into_iter()
+                    .map(|m| normalized.map_back(text, m))
+                    .collect();
+                Some(collected)
             }
+            None => self.collect_matches_in(compiled, text, config, stop),
+        }
+    }
 
-            redacted_text = pattern
-                .regex
-                .replace_all(&redacted_text, pattern.pii_type.redaction())
-                .to_string();
+    /// [`collect_matches_until`](Tork::collect_matches_until) on text already
+    /// normalized as `config` asks
+    fn collect_matches_in(
+        &self,
+        compiled: &Compiled,
+        text: &str,
+        config: &TorkConfig,
+        stop: Stop<'_>,
+    ) -> Option<Collected> {
+        let mut collected = self.collect_plain_matches(compiled, text, config, stop)?;
+        let placeholders = placeholder::placeholder_ranges(text, config);
+        if !placeholders.is_empty() {
+            let outside = |m: &PIIMatch| {
+                !placeholders
+                    .iter()
+                    .any(|p| m.start_index < p.end && p.start < m.end_index)
+            };
+            collected.matches.retain(outside);
+            collected.uncertain.retain(outside);
         }
+        if config.scan_encoded && !collected.timed_out {
+            let (mut encoded, timed_out) = self.encoded_matches(compiled, text, config, stop)?;
+            collected.timed_out = timed_out;
+            if !encoded.is_empty() {
+                let outside = |m: &PIIMatch| {
+                    !encoded
+                        .iter()
+                        .any(|e| m.start_index < e.end_index && e.start_index < m.end_index)
+                };
+                collected.matches.retain(outside);
+                collected.uncertain.retain(outside);
+                encoded.append(&mut collected.matches);
+                collected.matches = encoded;
+            }
+        }
+        Some(collected)
+    }
 
-        PIIDetectionResult {
-            has_pii: !matches.is_empty(),
-            types: detected_types.into_iter().collect(),
-            count: matches.len(),
-            matches,
-            redacted_text,
+    /// Base64 runs whose decoded text contains PII, as `EncodedPii` matches,
+    /// and whether the deadline cut the scan short
+    fn encoded_matches(
+        &self,
+        compiled: &Compiled,
+        text: &str,
+        config: &TorkConfig,
+        stop: Stop<'_>,
+    ) -> Option<(Vec<PIIMatch>, bool)> {
+        let mut matches = Vec::new();
+        for (start, end, decoded) in encoded::decoded_runs(text) {
+            let inner = self.collect_plain_matches(compiled, &decoded, config, stop)?;
+            if inner.timed_out {
+                return Some((matches, true));
+            }
+            if inner.matches.is_empty() {
+                continue;
+            }
+            let m = PIIMatch {
+                pii_type: PIIType::EncodedPii,
+                value: text[start..end].to_string(),
+                start_index: start,
+                end_index: end,
+                location: None,
+                confidence: 1.0,
+                groups: Vec::new(),
+            };
+            if config.accepts(&m, &compiled.allowlist) {
+                matches.push(m);
+            }
+        }
+        Some((matches, false))
+    }
+
+    /// [`collect_matches_until`](Tork::collect_matches_until) without decoding
+    /// encoded blobs
+    ///
+    /// Uncertain matches are only collected when
+    /// `config.uncertain_action` isn't `Allow`, and are kept only if they
+    /// survive overlap resolution against every match and overlap no
+    /// accepted one.
+    fn collect_plain_matches(
+        &self,
+        compiled: &Compiled,
+        text: &str,
+        config: &TorkConfig,
+        stop: Stop<'_>,
+    ) -> Option<Collected> {
+        let limit = config.max_matches.unwrap_or(usize::MAX);
+        let with_uncertain = config.uncertain_action != GovernanceAction::Allow;
+        let mut matches = Vec::new();
+        let mut uncertain = Vec::new();
+        let mut truncated = false;
+        let mut timed_out = false;
+        'detectors: for detector in self.all_detectors(&compiled.detectors) {
+            if stop.cancelled() {
+                return None;
+            }
+            if stop.deadline.is_some_and(|deadline| self.clock.now() >= deadline) {
+                timed_out = true;
+                break;
+            }
+            for m in detector.detect_iter(text) {
+                if stop.cancelled() {
+                    return None;
+                }
+                if with_uncertain && config.is_uncertain(&m, &compiled.allowlist) {
+                    uncertain.push(m);
+                    continue;
+                }
+                if !config.accepts(&m, &compiled.allowlist) {
+                    continue;
+                }
+                if matches.len() == limit {
+                    truncated = true;
+                    break 'detectors;
+                }
+                matches.push(m);
+            }
+        }
+        let matches = resolve_overlaps(text, matches, config);
+        if !uncertain.is_empty() {
+            let all = matches.iter().cloned().chain(uncertain).collect();
+            uncertain = resolve_overlaps(text, all, config);
+            uncertain.retain(|m| {
+                m.confidence < config.min_confidence
+                    && !matches
+                        .iter()
+                        .any(|a| a.start_index < m.end_index && m.start_index < a.end_index)
+            });
         }
+        Some(Collected {
+            matches,
+            truncated,
+            uncertain,
+            timed_out,
+        })
+    }
+
+    /// `detectors` followed by those added with [`add_detector`](Tork::add_detector)
+    fn all_detectors<'a>(&'a self, detectors: &'a [CompiledDetector]) -> impl Iterator<Item = &'a dyn Detector> {
+        let added = self.custom_detectors.iter().map(|d| d.as_ref() as &dyn Detector);
+        detectors.iter().map(CompiledDetector::detector).chain(added)
     }
 
-    /// Get current statistics
-    pub fn get_stats(&self) -> &TorkStats {
-        &self.stats
+    /// Get a snapshot of current statistics
+    pub fn get_stats(&self) -> TorkStats {
+        self.lock_stats().clone()
     }
 
     /// Reset statistics
     pub fn reset_stats(&mut self) {
-        self.stats = TorkStats::default();
+        *self.lock_stats() = TorkStats::default();
+    }
+
+    /// Lock the stats, recovering from a panic in another governing thread
+    fn lock_stats(&self) -> MutexGuard<'_, TorkStats> {
+        self.stats.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Lock the receipt chain head, recovering from a panic elsewhere
+    fn lock_chain(&self) -> MutexGuard<'_, Option<String>> {
+        self.last_receipt_hash.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Snapshot config, stats and the receipt chain head for persistence
+    ///
+    /// Detectors registered with [`add_detector`](Tork::add_detector), the
+    /// clock, the audit sink and the policy registry are not part of the state.
+    pub fn export_state(&self) -> TorkState {
+        TorkState {
+            config: TorkConfig::clone(&self.get_config()),
+            stats: self.get_stats(),
+            last_receipt_hash: self.lock_chain().clone(),
+        }
+    }
+
+    /// Restore a snapshot from [`export_state`](Tork::export_state)
+    ///
+    /// Detectors are rebuilt from the restored config, including its custom
+    /// patterns, and the next receipt links to the snapshot's last one.
+    /// Added detectors, the clock and the audit sink are kept. A config that
+    /// [`set_config`](Tork::set_config) rejects is an error, and nothing is
+    /// restored.
+    pub fn import_state(&mut self, state: TorkState) -> Result<(), Error> {
+        self.set_config(state.config)?;
+        *self.lock_stats() = state.stats;
+        *self.lock_chain() = state.last_receipt_hash;
+        Ok(())
+    }
+
+    /// Current config and detectors, cloned out so the lock isn't held
+    /// while they're used
+    fn compiled(&self) -> Arc<Compiled> {
+        Arc::clone(&self.compiled.read().unwrap_or_else(PoisonError::into_inner))
     }
 
     /// Get current configuration
-    pub fn get_config(&self) -> &TorkConfig {
-        &self.config
+    ///
+    /// The returned config is a snapshot; a later
+    /// [`reload_config`](Tork::reload_config) doesn't change it.
+    pub fn get_config(&self) -> Arc<TorkConfig> {
+        Arc::clone(&self.compiled().config)
     }
 
     /// Update configuration
-    pub fn set_config(&mut self, config: TorkConfig) {
-        self.config = config;
+    ///
+    /// Like [`reload_config`](Tork::reload_config), the config is validated
+    /// and its detectors compiled first; on error the current config stays.
+    pub fn set_config(&mut self, config: TorkConfig) -> Result<(), Error> {
+        self.reload_config(config)
+    }
+
+    /// Swap in `config`, a variation of the current one whose patterns have
+    /// already compiled
+    fn replace_config(&mut self, config: TorkConfig) {
+        let detectors = config_detectors(&config, Some(&self.clock)).expect("the current config's patterns compile");
+        *self.compiled.get_mut().unwrap_or_else(PoisonError::into_inner) = Compiled::new(config, detectors);
+    }
+
+    /// Replace the policy, keeping the current detectors and detection config
+    pub fn set_policy(&mut self, policy: Policy) {
+        let compiled = self.compiled.get_mut().unwrap_or_else(PoisonError::into_inner);
+        let config = TorkConfig::from_parts(compiled.config.detection(), policy);
+        *compiled = Compiled::with_detectors(Arc::new(config), Arc::clone(&compiled.detectors));
+    }
+
+    /// Replace the detection config and rebuild detectors, keeping the
+    /// current policy
+    ///
+    /// Fails, keeping the current config, as [`set_config`](Tork::set_config)
+    /// does.
+    pub fn set_detection_config(&mut self, detection: DetectionConfig) -> Result<(), Error> {
+        let policy = self.get_config().policy();
+        self.set_config(TorkConfig::from_parts(detection, policy))
+    }
+
+    /// Replace the configuration while other threads keep governing
+    ///
+    /// The config is validated and its detectors compiled before anything
+    /// changes, then config and detectors are swapped together. Calls already
+    /// running finish under the old policy and every call starting after this
+    /// returns uses the new one. On error the current policy stays in place.
+    pub fn reload_config(&self, config: TorkConfig) -> Result<(), Error> {
+        config.validate()?;
+        let detectors = config_detectors(&config, Some(&self.clock))?;
+        let compiled = Compiled::new(config, detectors);
+        *self.compiled.write().unwrap_or_else(PoisonError::into_inner) = compiled;
+        Ok(())
+    }
+
+    /// Stop detecting `pii_type`, including matches from added detectors
+    pub fn disable_type(&mut self, pii_type: PIIType) {
+        let mut config = TorkConfig::clone(&self.get_config());
+        if config.enabled_types.remove(&pii_type) {
+            self.replace_config(config);
+        }
+    }
+
+    /// Resume detecting `pii_type`
+    pub fn enable_type(&mut self, pii_type: PIIType) {
+        let mut config = TorkConfig::clone(&self.get_config());
+        if config.enabled_types.insert(pii_type) {
+            self.replace_config(config);
+        }
     }
 }
 
+#[cfg(feature = "std")]
 impl Default for Tork {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Copies the config, policies, clock and chain head, sharing compiled and
+/// added detectors and the audit sink rather than rebuilding them
+///
+/// Stats are not copied: the clone starts from [`TorkStats::default`] and
+/// counts only its own calls. Receipts from the clone and the original both
+/// link to the chain head at the time of cloning, so the chain forks there.
+#[cfg(feature = "std")]
+impl Clone for Tork {
+    fn clone(&self) -> Self {
+        Tork {
+            compiled: RwLock::new(self.compiled()),
+            stats: Mutex::new(TorkStats::default()),
+            custom_detectors: self.custom_detectors.clone(),
+            clock: Arc::clone(&self.clock),
+            audit_sink: self.audit_sink.clone(),
+            last_receipt_hash: Mutex::new(self.lock_chain().clone()),
+            policies: self.policies.clone(),
+        }
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use super::*;
+    use crate::core_detect::luhn_valid;
 
     #[test]
     fn test_detect_ssn() {
@@ -515,7 +4423,7 @@ mod tests {
 
     #[test]
     fn test_tork_govern_with_pii() {
-        let mut tork = Tork::new();
+        let tork = Tork::new();
         let result = tork.govern("My SSN is 123-45-6789");
         assert_eq!(result.action, GovernanceAction::Redact);
         assert_eq!(result.output, "My SSN is [SSN_REDACTED]");
@@ -524,7 +4432,7 @@ mod tests {
 
     #[test]
     fn test_tork_govern_without_pii() {
-        let mut tork = Tork::new();
+        let tork = Tork::new();
         let result = tork.govern("Hello world");
         assert_eq!(result.action, GovernanceAction::Allow);
         assert_eq!(result.output, "Hello world");
@@ -532,7 +4440,7 @@ mod tests {
 
     #[test]
     fn test_tork_receipt_generation() {
-        let mut tork = Tork::new();
+        let tork = Tork::new();
         let result = tork.govern("Test input");
         assert!(result.receipt.receipt_id.starts_with("rcpt_"));
         assert!(result.receipt.input_hash.starts_with("sha256:"));
@@ -541,7 +4449,7 @@ mod tests {
 
     #[test]
     fn test_tork_statistics() {
-        let mut tork = Tork::new();
+        let tork = Tork::new();
         tork.govern("Text 1");
         tork.govern("SSN: 123-45-6789");
         tork.govern("Text 3");
@@ -551,6 +4459,140 @@ mod tests {
         assert_eq!(stats.total_pii_detected, 1);
     }
 
+    #[test]
+    fn test_deny_output_default_is_empty() {
+        let config = TorkConfig {
+            default_action: GovernanceAction::Deny,
+            ..Default::default()
+        };
+        let tork = Tork::with_config(config);
+        let result = tork.govern("My SSN is 123-45-6789");
+        assert_eq!(result.action, GovernanceAction::Deny);
+        assert_eq!(result.output, "");
+        assert_eq!(result.receipt.output_hash, hash_text(""));
+    }
+
+    #[test]
+    fn test_luhn_valid() {
+        for number in ["3782 822463 10005", "3056 930902 5904", "4111-1111-1111-1111", "4222222222222"] {
+            assert!(luhn_valid(number), "{}", number);
+        }
+        assert!(!luhn_valid("4111-1111-1111-1112"));
+    }
+
+    #[test]
+    fn test_redact_matches_prefers_earlier_detector() {
+        let text = "id 5551234567";
+        let matches = vec![
+            PIIMatch {
+                pii_type: PIIType::Phone,
+                value: "5551234567".into(),
+                start_index: 3,
+                end_index: 13,
+                location: None,
+                confidence: 1.0,
+                groups: Vec::new(),
+            },
+            PIIMatch {
+                pii_type: PIIType::BankAccount,
+                value: "5551234567".into(),
+                start_index: 3,
+                end_index: 13,
+                location: None,
+                confidence: 1.0,
+                groups: Vec::new(),
+            },
+        ];
+        let mut redactor = Redactor::new(&TorkConfig::default());
+        assert_eq!(redact_matches(text, &matches, &mut redactor).0, "id [PHONE_REDACTED]");
+    }
+
+    #[test]
+    fn test_overlap_sweep_agrees_with_pairwise_check() {
+        let types = [PIIType::Ssn, PIIType::Phone, PIIType::BankAccount, PIIType::Email];
+        let text = "x".repeat(64);
+        let matches: Vec<PIIMatch> = (0..200usize)
+            .map(|i| {
+                let start = (i * 37) % 60;
+                let end = (start + (i * 11) % 9).min(64);
+                PIIMatch {
+                    pii_type: types[i % types.len()],
+                    value: text[start..end].to_string(),
+                    start_index: start,
+                    end_index: end,
+                    location: None,
+                    confidence: 1.0,
+                    groups: Vec::new(),
+                }
+            })
+            .collect();
+        let config = TorkConfig::default();
+        let overlaps = |a: &PIIMatch, b: &PIIMatch| a.start_index < b.end_index && b.start_index < a.end_index;
+
+        let expected: Vec<(usize, usize)> = matches
+            .iter()
+            .filter(|m| !matches.iter().any(|x| overlaps(x, m) && config.outranks(x.pii_type, m.pii_type)))
+            .map(|m| (m.start_index, m.end_index))
+            .collect();
+        let resolved: Vec<(usize, usize)> = resolve_overlaps(&text, matches.clone(), &config)
+            .iter()
+            .map(|m| (m.start_index, m.end_index))
+            .collect();
+        assert_eq!(resolved, expected);
+
+        let mut expected: Vec<&PIIMatch> = Vec::new();
+        for m in &matches {
+            if !expected.iter().any(|c| overlaps(c, m)) {
+                expected.push(m);
+            }
+        }
+        expected.sort_by_key(|m| m.start_index);
+        let claimed = core_detect::claim(&matches);
+        assert_eq!(claimed.len(), expected.len());
+        assert!(claimed.iter().zip(&expected).all(|(a, b)| std::ptr::eq(*a, *b)));
+    }
+
+    #[test]
+    fn test_pii_type_all_is_exhaustive() {
+        // Adding a variant without listing it in `all()` fails to compile here
+        let count = PIIType::all()
+            .iter()
+            .map(|t| match t {
+                PIIType::Ssn
+                | PIIType::Ein
+                | PIIType::CreditCard
+                | PIIType::Email
+                | PIIType::Phone
+                | PIIType::Address
+                | PIIType::PostalCode
+                | PIIType::IpAddress
+                | PIIType::DateOfBirth
+                | PIIType::Passport
+                | PIIType::DriversLicense
+                | PIIType::Vin
+                | PIIType::BankAccount
+                | PIIType::CryptoWallet
+                | PIIType::Name
+                | PIIType::EncodedPii
+                | PIIType::Custom => 1,
+            })
+            .sum::<usize>();
+        assert_eq!(count, 17);
+        let unique: HashSet<_> = PIIType::all().iter().collect();
+        assert_eq!(unique.len(), PIIType::all().len());
+    }
+
+    #[test]
+    fn test_latency_bucket_bounds() {
+        for value in [0, 1, 15, 16, 17, 31, 32, 1_000, 123_456, u64::MAX / 3, u64::MAX] {
+            let index = LatencyHistogram::bucket_index(value);
+            let upper = LatencyHistogram::bucket_upper_bound(index);
+            assert!(upper >= value, "{} -> {}", value, upper);
+            assert!(upper - value <= value / 16, "{} -> {}", value, upper);
+        }
+        assert!(LatencyHistogram::bucket_index(u64::MAX) < 976);
+    }
+
     #[test]
     fn test_hash_text_consistency() {
         let hash1 = hash_text("test");
@@ -0,0 +1,128 @@
+//! Text-node extraction for Markdown and HTML input
+//!
+//! [`Tork::govern_markup`](crate::Tork::govern_markup) only redacts inside the
+//! ranges returned here, so link targets, attributes and tag syntax are never
+//! rewritten.
+
+use pulldown_cmark::{Event, Parser};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Markup language of governed input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Markup {
+    /// CommonMark; text and inline code are scanned, link and image
+    /// destinations are not
+    Markdown,
+    /// HTML; character data between tags is scanned, tags, attributes,
+    /// comments and `<script>`/`<style>` bodies are not
+    Html,
+}
+
+/// Byte ranges of `input` holding human-readable text, in order
+pub(crate) fn text_ranges(input: &str, markup: Markup) -> Vec<Range<usize>> {
+    match markup {
+        Markup::Markdown => markdown_text_ranges(input),
+        Markup::Html => html_text_ranges(input),
+    }
+}
+
+fn markdown_text_ranges(input: &str) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    for (event, range) in Parser::new(input).into_offset_iter() {
+        if !matches!(event, Event::Text(_) | Event::Code(_)) {
+            continue;
+        }
+        // The parser splits text at characters that might start inline
+        // syntax, so rejoin adjacent pieces before scanning.
+        match ranges.last_mut() {
+            Some(last) if last.end == range.start => last.end = range.end,
+            _ => ranges.push(range),
+        }
+    }
+    ranges
+}
+
+fn html_text_ranges(input: &str) -> Vec<Range<usize>> {
+    let bytes = input.as_bytes();
+    let mut ranges = Vec::new();
+    let mut pos = 0;
+    let mut text_start = 0;
+
+    while pos < bytes.len() {
+        if bytes[pos] != b'<' {
+            pos += 1;
+            continue;
+        }
+        if text_start < pos {
+            ranges.push(text_start..pos);
+        }
+
+        let rest = &input[pos..];
+        pos = if rest.starts_with("<!--") {
+            rest.find("-->").map_or(bytes.len(), |end| pos + end + 3)
+        } else {
+            let tag_end = rest.find('>').map_or(bytes.len(), |end| pos + end + 1);
+            match raw_text_element(rest) {
+                Some(name) => find_closing_tag(input, tag_end, name),
+                None => tag_end,
+            }
+        };
+        text_start = pos;
+    }
+    if text_start < bytes.len() {
+        ranges.push(text_start..bytes.len());
+    }
+    ranges
+}
+
+/// Name of the element if `tag` opens one whose body isn't text
+fn raw_text_element(tag: &str) -> Option<&'static str> {
+    let name: String = tag[1..]
+        .chars()
+        .take_while(|c| c.is_ascii_alphanumeric())
+        .collect();
+    ["script", "style"]
+        .into_iter()
+        .find(|raw| name.eq_ignore_ascii_case(raw))
+}
+
+/// Position just after `</name>` at or after `from`, or the end of input
+fn find_closing_tag(input: &str, from: usize, name: &str) -> usize {
+    let closing = format!("</{}", name);
+    let lowered = input[from..].to_ascii_lowercase();
+    match lowered.find(&closing) {
+        Some(start) => {
+            let start = from + start;
+            input[start..]
+                .find('>')
+                .map_or(input.len(), |end| start + end + 1)
+        }
+        None => input.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(input: &str, markup: Markup) -> Vec<&str> {
+        text_ranges(input, markup)
+            .into_iter()
+            .map(|r| &input[r])
+            .collect()
+    }
+
+    #[test]
+    fn test_markdown_link_text_only() {
+        let input = "See [docs](https://example.com/a_b) now";
+        assert_eq!(texts(input, Markup::Markdown), vec!["See ", "docs", " now"]);
+    }
+
+    #[test]
+    fn test_html_skips_tags_comments_and_scripts() {
+        let input = "<p class=\"x\">Hi <b>there</b><!-- a@b.co --></p><script>var a = 1;</script>end";
+        assert_eq!(texts(input, Markup::Html), vec!["Hi ", "there", "end"]);
+    }
+}
@@ -0,0 +1,43 @@
+//! `metrics` instrumentation for governance operations
+//!
+//! Every counted call updates the recorder installed with the `metrics`
+//! crate, so any exporter (such as `metrics-exporter-prometheus`) can scrape
+//! the same totals [`TorkStats`](crate::TorkStats) keeps:
+//!
+//! - `tork_govern_total`: governance calls
+//! - `tork_pii_detected_total`: calls that found PII
+//! - `tork_action_total{action}`: calls by their most severe action
+//! - `tork_processing_seconds`: histogram of processing time
+//!
+//! The only label is the action name. Matched values, types and receipt IDs
+//! are never recorded.
+
+use crate::GovernanceAction;
+use ::metrics::{counter, describe_counter, describe_histogram, histogram, Unit};
+
+pub(crate) const GOVERN_TOTAL: &str = "tork_govern_total";
+pub(crate) const PII_DETECTED_TOTAL: &str = "tork_pii_detected_total";
+pub(crate) const ACTION_TOTAL: &str = "tork_action_total";
+pub(crate) const PROCESSING_SECONDS: &str = "tork_processing_seconds";
+
+/// Register descriptions and units for the Tork metrics with the installed
+/// recorder
+///
+/// Optional: metrics are emitted without it, but exporters then omit their
+/// help text. Call it once after installing the recorder.
+pub fn describe_metrics() {
+    describe_counter!(GOVERN_TOTAL, Unit::Count, "Governance calls");
+    describe_counter!(PII_DETECTED_TOTAL, Unit::Count, "Governance calls that found PII");
+    describe_counter!(ACTION_TOTAL, Unit::Count, "Governance calls by their most severe action");
+    describe_histogram!(PROCESSING_SECONDS, Unit::Seconds, "Time spent governing a call");
+}
+
+/// Count one governance call that took `processing_time_ns`
+pub(crate) fn record_call(has_pii: bool, action: GovernanceAction, processing_time_ns: u64) {
+    counter!(GOVERN_TOTAL).increment(1);
+    if has_pii {
+        counter!(PII_DETECTED_TOTAL).increment(1);
+    }
+    counter!(ACTION_TOTAL, "action" => action.as_str()).increment(1);
+    histogram!(PROCESSING_SECONDS).record(processing_time_ns as f64 / 1e9);
+}
@@ -30,7 +30,9 @@
 //! }
 //! ```
 
-use super::{extract_content, should_protect_path, should_skip_path, ErrorResponse, MiddlewareConfig, SharedTork};
+use super::{
+    process_request, ErrorResponse, MiddlewareConfig, MiddlewareCounters, MiddlewareOutcome, SharedGovernor, SharedTork,
+};
 use crate::{GovernanceAction, GovernanceResult, Tork};
 use std::sync::{Arc, Mutex};
 
@@ -39,38 +41,52 @@ pub type TorkResult = GovernanceResult;
 
 /// Actix Web middleware configuration
 pub struct TorkMiddleware {
-    tork: SharedTork,
+    governor: SharedGovernor,
+    tork: Option<SharedTork>,
     config: MiddlewareConfig,
+    counters: Arc<MiddlewareCounters>,
 }
 
 impl TorkMiddleware {
     /// Create new middleware with default configuration
     pub fn new() -> Self {
-        Self {
-            tork: Arc::new(Mutex::new(Tork::new())),
-            config: MiddlewareConfig::default(),
-        }
+        Self::with_config(MiddlewareConfig::default())
     }
 
     /// Create new middleware with custom configuration
     pub fn with_config(config: MiddlewareConfig) -> Self {
-        Self {
-            tork: Arc::new(Mutex::new(Tork::new())),
-            config,
-        }
+        Self::with_tork_and_config(Arc::new(Mutex::new(Tork::new())), config)
     }
 
     /// Create new middleware with existing Tork instance
     pub fn with_tork(tork: SharedTork) -> Self {
-        Self {
-            tork,
-            config: MiddlewareConfig::default(),
-        }
+        Self::with_tork_and_config(tork, MiddlewareConfig::default())
     }
 
     /// Create new middleware with custom Tork and config
     pub fn with_tork_and_config(tork: SharedTork, config: MiddlewareConfig) -> Self {
-        Self { tork, config }
+        Self {
+            governor: tork.clone(),
+            tork: Some(tork),
+            config,
+            counters: Arc::default(),
+        }
+    }
+
+    /// Create new middleware governing through `governor`, e.g. a
+    /// [`NoOpGovernor`](crate::NoOpGovernor) in development
+    pub fn with_governor(governor: SharedGovernor) -> Self {
+        Self::with_governor_and_config(governor, MiddlewareConfig::default())
+    }
+
+    /// Create new middleware with custom governor and config
+    pub fn with_governor_and_config(governor: SharedGovernor, config: MiddlewareConfig) -> Self {
+        Self {
+            governor,
+            tork: None,
+            config,
+            counters: Arc::default(),
+        }
     }
 
     /// Get reference to config
@@ -79,32 +95,63 @@ impl TorkMiddleware {
     }
 
     /// Get reference to shared Tork
+    ///
+    /// # Panics
+    ///
+    /// If built with a governor rather than a Tork.
+    #[deprecated(note = "use `shared_tork`, which returns `None` when built with a governor")]
     pub fn tork(&self) -> &SharedTork {
-        &self.tork
+        self.shared_tork().expect("built with a governor, not a Tork")
     }
 
-    /// Process request body and return governance result
-    pub fn process(&self, method: &str, path: &str, body: &str) -> Option<GovernanceResult> {
-        // Only process POST, PUT, PATCH
-        if !["POST", "PUT", "PATCH"].contains(&method) {
-            return None;
-        }
+    /// Get reference to shared Tork, or `None` if built with a governor
+    pub fn shared_tork(&self) -> Option<&SharedTork> {
+        self.tork.as_ref()
+    }
 
-        // Check paths
-        if should_skip_path(path, &self.config) {
-            return None;
-        }
+    /// Get reference to the governor requests go through
+    pub fn governor(&self) -> &SharedGovernor {
+        &self.governor
+    }
 
-        if !should_protect_path(path, &self.config) {
-            return None;
-        }
+    /// Get the counts of requests let through ungoverned
+    pub fn counters(&self) -> &MiddlewareCounters {
+        &self.counters
+    }
 
-        // Extract content
-        let content = extract_content(body, &self.config)?;
+    /// Process request body and return governance result
+    ///
+    /// `content_type` is the request's `Content-Type` header, see
+    /// [`extract_content_typed`](super::extract_content_typed). Returns
+    /// `None` for skipped requests and bodies without content; use
+    /// [`process_verbose`](Self::process_verbose) to tell them apart.
+    pub fn process(
+        &self,
+        method: &str,
+        path: &str,
+        content_type: Option<&str>,
+        body: &str,
+    ) -> Option<GovernanceResult> {
+        self.process_verbose(method, path, content_type, body).into_result()
+    }
 
-        // Govern content
-        let mut tork = self.tork.lock().unwrap();
-        Some(tork.govern(&content))
+    /// Process request body, reporting why it wasn't governed if it wasn't
+    pub fn process_verbose(
+        &self,
+        method: &str,
+        path: &str,
+        content_type: Option<&str>,
+        body: &str,
+    ) -> MiddlewareOutcome {
+        process_request(
+            self.governor.as_ref(),
+            &self.config,
+            &self.counters,
+            method,
+            path,
+            content_type,
+            body,
+        )
     }
 
     /// Check if result should block the request
@@ -127,8 +174,10 @@ impl Default for TorkMiddleware {
 impl Clone for TorkMiddleware {
     fn clone(&self) -> Self {
         Self {
-            tork: Arc::clone(&self.tork),
+            governor: Arc::clone(&self.governor),
+            tork: self.tork.clone(),
             config: self.config.clone(),
+            counters: Arc::clone(&self.counters),
         }
     }
 }
@@ -205,6 +254,7 @@ mod tests {
         let result = middleware.process(
             "POST",
             "/api/chat",
+            Some("application/json"),
             r#"{"content": "My SSN is 123-45-6789"}"#,
         );
 
@@ -213,17 +263,44 @@ mod tests {
         assert!(result.pii.has_pii);
     }
 
+    #[test]
+    fn test_process_recovers_from_poisoned_mutex() {
+        let middleware = TorkMiddleware::new();
+        let tork = middleware.shared_tork().unwrap().clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = tork.lock().unwrap();
+            panic!("poison the shared Tork");
+        })
+        .join();
+        assert!(middleware.shared_tork().unwrap().is_poisoned());
+
+        let result = middleware.process(
+            "POST",
+            "/api/chat",
+            Some("application/json"),
+            r#"{"content": "My SSN is 123-45-6789"}"#,
+        );
+        assert!(result.unwrap().pii.has_pii);
+    }
+
+    #[test]
+    fn test_process_plain_text_body() {
+        let middleware = TorkMiddleware::new();
+        let result = middleware.process("POST", "/api/chat", Some("text/plain"), "My SSN is 123-45-6789");
+        assert_eq!(result.unwrap().output, "My SSN is [SSN_REDACTED]");
+    }
+
     #[test]
     fn test_skip_get_request() {
         let middleware = TorkMiddleware::new();
-        let result = middleware.process("GET", "/api/chat", r#"{"content": "test"}"#);
+        let result = middleware.process("GET", "/api/chat", Some("application/json"), r#"{"content": "test"}"#);
         assert!(result.is_none());
     }
 
     #[test]
     fn test_skip_unprotected_path() {
         let middleware = TorkMiddleware::new();
-        let result = middleware.process("POST", "/health", r#"{"content": "test"}"#);
+        let result = middleware.process("POST", "/health", Some("application/json"), r#"{"content": "test"}"#);
         assert!(result.is_none());
     }
 }
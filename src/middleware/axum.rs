@@ -36,7 +36,9 @@
 //! }
 //! ```
 
-use super::{extract_content, should_protect_path, should_skip_path, ErrorResponse, MiddlewareConfig, SharedTork};
+use super::{
+    process_request, ErrorResponse, MiddlewareConfig, MiddlewareCounters, MiddlewareOutcome, SharedGovernor, SharedTork,
+};
 use crate::{GovernanceAction, GovernanceResult, Tork};
 use std::sync::{Arc, Mutex};
 
@@ -46,38 +48,52 @@ pub type TorkExtension = GovernanceResult;
 /// Axum layer for Tork governance
 #[derive(Clone)]
 pub struct TorkLayer {
-    tork: SharedTork,
+    governor: SharedGovernor,
+    tork: Option<SharedTork>,
     config: MiddlewareConfig,
+    counters: Arc<MiddlewareCounters>,
 }
 
 impl TorkLayer {
     /// Create new layer with default configuration
     pub fn new() -> Self {
-        Self {
-            tork: Arc::new(Mutex::new(Tork::new())),
-            config: MiddlewareConfig::default(),
-        }
+        Self::with_config(MiddlewareConfig::default())
     }
 
     /// Create new layer with custom configuration
     pub fn with_config(config: MiddlewareConfig) -> Self {
-        Self {
-            tork: Arc::new(Mutex::new(Tork::new())),
-            config,
-        }
+        Self::with_tork_and_config(Arc::new(Mutex::new(Tork::new())), config)
     }
 
     /// Create new layer with existing Tork instance
     pub fn with_tork(tork: SharedTork) -> Self {
-        Self {
-            tork,
-            config: MiddlewareConfig::default(),
-        }
+        Self::with_tork_and_config(tork, MiddlewareConfig::default())
     }
 
     /// Create new layer with custom Tork and config
     pub fn with_tork_and_config(tork: SharedTork, config: MiddlewareConfig) -> Self {
-        Self { tork, config }
+        Self {
+            governor: tork.clone(),
+            tork: Some(tork),
+            config,
+            counters: Arc::default(),
+        }
+    }
+
+    /// Create new layer governing through `governor`, e.g. a
+    /// [`NoOpGovernor`](crate::NoOpGovernor) in development
+    pub fn with_governor(governor: SharedGovernor) -> Self {
+        Self::with_governor_and_config(governor, MiddlewareConfig::default())
+    }
+
+    /// Create new layer with custom governor and config
+    pub fn with_governor_and_config(governor: SharedGovernor, config: MiddlewareConfig) -> Self {
+        Self {
+            governor,
+            tork: None,
+            config,
+            counters: Arc::default(),
+        }
     }
 
     /// Get reference to config
@@ -86,32 +102,63 @@ impl TorkLayer {
     }
 
     /// Get reference to shared Tork
+    ///
+    /// # Panics
+    ///
+    /// If built with a governor rather than a Tork.
+    #[deprecated(note = "use `shared_tork`, which returns `None` when built with a governor")]
     pub fn tork(&self) -> &SharedTork {
-        &self.tork
+        self.shared_tork().expect("built with a governor, not a Tork")
     }
 
-    /// Process request body and return governance result
-    pub fn process(&self, method: &str, path: &str, body: &str) -> Option<GovernanceResult> {
-        // Only process POST, PUT, PATCH
-        if !["POST", "PUT", "PATCH"].contains(&method) {
-            return None;
-        }
+    /// Get reference to shared Tork, or `None` if built with a governor
+    pub fn shared_tork(&self) -> Option<&SharedTork> {
+        self.tork.as_ref()
+    }
 
-        // Check paths
-        if should_skip_path(path, &self.config) {
-            return None;
-        }
+    /// Get reference to the governor requests go through
+    pub fn governor(&self) -> &SharedGovernor {
+        &self.governor
+    }
 
-        if !should_protect_path(path, &self.config) {
-            return None;
-        }
+    /// Get the counts of requests let through ungoverned
+    pub fn counters(&self) -> &MiddlewareCounters {
+        &self.counters
+    }
 
-        // Extract content
-        let content = extract_content(body, &self.config)?;
+    /// Process request body and return governance result
+    ///
+    /// `content_type` is the request's `Content-Type` header, see
+    /// [`extract_content_typed`](super::extract_content_typed). Returns
+    /// `None` for skipped requests and bodies without content; use
+    /// [`process_verbose`](Self::process_verbose) to tell them apart.
+    pub fn process(
+        &self,
+        method: &str,
+        path: &str,
+        content_type: Option<&str>,
+        body: &str,
+    ) -> Option<GovernanceResult> {
+        self.process_verbose(method, path, content_type, body).into_result()
+    }
 
-        // Govern content
-        let mut tork = self.tork.lock().unwrap();
-        Some(tork.govern(&content))
+    /// Process request body, reporting why it wasn't governed if it wasn't
+    pub fn process_verbose(
+        &self,
+        method: &str,
+        path: &str,
+        content_type: Option<&str>,
+        body: &str,
+    ) -> MiddlewareOutcome {
+        process_request(
+            self.governor.as_ref(),
+            &self.config,
+            &self.counters,
+            method,
+            path,
+            content_type,
+            body,
+        )
     }
 
     /// Check if result should block the request
@@ -186,6 +233,8 @@ pub struct TorkResultExtractor(pub Option<GovernanceResult>);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::middleware::{create_shared_tork, middleware_response, Enforcement, InvalidJsonPolicy, RespondMode, SkipReason};
+    use crate::{NoOpGovernor, PIIType, TorkConfig, NO_OP_POLICY_VERSION};
 
     #[test]
     fn test_layer_creation() {
@@ -199,6 +248,7 @@ mod tests {
         let result = layer.process(
             "POST",
             "/api/chat",
+            Some("application/json"),
             r#"{"content": "My email is test@example.com"}"#,
         );
 
@@ -207,28 +257,213 @@ mod tests {
         assert!(result.pii.has_pii);
     }
 
+    #[test]
+    fn test_process_recovers_from_poisoned_mutex() {
+        let layer = TorkLayer::new();
+        let tork = layer.shared_tork().unwrap().clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = tork.lock().unwrap();
+            panic!("poison the shared Tork");
+        })
+        .join();
+        assert!(layer.shared_tork().unwrap().is_poisoned());
+
+        let result = layer.process(
+            "POST",
+            "/api/chat",
+            Some("application/json"),
+            r#"{"content": "My SSN is 123-45-6789"}"#,
+        );
+        assert!(result.unwrap().pii.has_pii);
+    }
+
+    #[test]
+    fn test_process_urlencoded_body() {
+        let layer = TorkLayer::new();
+        let result = layer.process(
+            "POST",
+            "/api/chat",
+            Some("application/x-www-form-urlencoded"),
+            "prompt=My+SSN+is+123-45-6789",
+        );
+        assert_eq!(result.unwrap().output, "My SSN is [SSN_REDACTED]");
+    }
+
     #[test]
     fn test_skip_get_request() {
         let layer = TorkLayer::new();
-        let result = layer.process("GET", "/api/chat", r#"{"content": "test"}"#);
+        let result = layer.process("GET", "/api/chat", Some("application/json"), r#"{"content": "test"}"#);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_process_verbose_non_json_body() {
+        let layer = TorkLayer::new();
+        let outcome = layer.process_verbose("POST", "/api/chat", None, "SSN 123-45-6789");
+        assert!(matches!(outcome, MiddlewareOutcome::NoContent));
+        assert_eq!(layer.counters().unparsed(), 1);
+        assert_eq!(layer.counters().skipped(), 0);
+    }
+
+    #[test]
+    fn test_process_verbose_get_is_skipped() {
+        let layer = TorkLayer::new();
+        let outcome = layer.process_verbose("GET", "/api/chat", Some("application/json"), r#"{"content": "test"}"#);
+        assert!(matches!(outcome, MiddlewareOutcome::Skipped(SkipReason::Method)));
+
+        let outcome = layer.process_verbose("POST", "/health", Some("application/json"), r#"{"content": "test"}"#);
+        assert!(matches!(outcome, MiddlewareOutcome::Skipped(SkipReason::UnprotectedPath)));
+        assert_eq!(layer.clone().counters().skipped(), 2);
+    }
+
+    #[test]
+    fn test_process_verbose_governed() {
+        let layer = TorkLayer::new();
+        let outcome = layer.process_verbose(
+            "POST",
+            "/api/chat",
+            Some("application/json"),
+            r#"{"content": "My SSN is 123-45-6789"}"#,
+        );
+        match outcome {
+            MiddlewareOutcome::Governed(result) => assert_eq!(result.output, "My SSN is [SSN_REDACTED]"),
+            other => panic!("expected Governed, got {:?}", other),
+        }
+        assert_eq!(layer.counters().skipped() + layer.counters().unparsed(), 0);
+    }
+
+    #[test]
+    fn test_process_invalid_json_deny_policy() {
+        let config = MiddlewareConfig {
+            invalid_json: InvalidJsonPolicy::Deny,
+            ..Default::default()
+        };
+        let layer = TorkLayer::with_config(config);
+        let result = layer
+            .process("POST", "/api/chat", Some("application/json"), r#"{"content": "#)
+            .unwrap();
+        assert!(TorkLayer::should_block(&result));
+        assert_eq!(layer.counters().unparsed(), 1);
+
+        let result = layer.process("POST", "/api/chat", Some("application/json"), "{}");
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_process_monitor_forwards_original() {
+        let config = MiddlewareConfig {
+            enforcement: Enforcement::Monitor,
+            ..Default::default()
+        };
+        let layer = TorkLayer::with_config(config);
+        let result = layer
+            .process(
+                "POST",
+                "/api/chat",
+                Some("application/json"),
+                r#"{"content": "My SSN is 123-45-6789"}"#,
+            )
+            .unwrap();
+        assert_eq!(result.action, GovernanceAction::Allow);
+        assert_eq!(result.output, "My SSN is 123-45-6789");
+        assert!(!result.modified);
+        assert!(!result.was_modified());
+        assert_eq!(result.pii.types, vec![PIIType::Ssn]);
+        assert_eq!(result.receipt.action, GovernanceAction::Redact);
+        assert!(!TorkLayer::should_block(&result));
+        assert!(middleware_response(&result, RespondMode::RespondRedacted).is_none());
+    }
+
+    #[test]
+    fn test_process_monitor_never_blocks() {
+        let mut tork = Tork::new();
+        tork.set_config(TorkConfig {
+            default_action: GovernanceAction::Deny,
+            ..Default::default()
+        })
+        .unwrap();
+        let config = MiddlewareConfig {
+            enforcement: Enforcement::Monitor,
+            invalid_json: InvalidJsonPolicy::Deny,
+            ..Default::default()
+        };
+        let layer = TorkLayer::with_tork_and_config(Arc::new(Mutex::new(tork)), config);
+        let result = layer
+            .process("POST", "/api/chat", Some("text/plain"), "card 4111-1111-1111-1111")
+            .unwrap();
+        assert_eq!(result.action, GovernanceAction::Allow);
+        assert_eq!(result.output, "card 4111-1111-1111-1111");
+        assert_eq!(result.receipt.action, GovernanceAction::Deny);
+
+        let result = layer
+            .process("POST", "/api/chat", Some("application/json"), r#"{"content": "#)
+            .unwrap();
+        assert!(!TorkLayer::should_block(&result));
+        assert_eq!(result.output, r#"{"content": "#);
+    }
+
     #[test]
     fn test_custom_config() {
         let config = MiddlewareConfig {
             protected_paths: vec!["/v1/".to_string()],
             skip_paths: vec!["/v1/health".to_string()],
             content_fields: vec!["data".to_string()],
+            ..Default::default()
         };
         let layer = TorkLayer::with_config(config);
 
         let result = layer.process(
             "POST",
             "/v1/chat",
+            Some("application/json"),
             r#"{"data": "SSN: 123-45-6789"}"#,
         );
 
         assert!(result.is_some());
     }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_tork_accessor() {
+        let tork = create_shared_tork();
+        let layer = TorkLayer::with_tork(tork.clone());
+        assert!(Arc::ptr_eq(layer.tork(), &tork));
+    }
+
+    #[test]
+    fn test_no_op_governor_passes_through() {
+        let layer = TorkLayer::with_governor(Arc::new(NoOpGovernor));
+        assert!(layer.shared_tork().is_none());
+        let result = layer
+            .process("POST", "/api/chat", Some("application/json"), r#"{"content": "SSN: 123-45-6789"}"#)
+            .unwrap();
+        assert_eq!(result.action, GovernanceAction::Allow);
+        assert_eq!(result.output, "SSN: 123-45-6789");
+        assert!(!result.pii.has_pii);
+        assert_eq!(result.receipt.policy_version, NO_OP_POLICY_VERSION);
+        assert!(middleware_response(&result, RespondMode::RespondRedacted).is_none());
+    }
+
+    #[test]
+    fn test_no_op_governor_allows_unparsed_body() {
+        let config = MiddlewareConfig {
+            invalid_json: InvalidJsonPolicy::Deny,
+            ..Default::default()
+        };
+        let layer = TorkLayer::with_governor_and_config(Arc::new(NoOpGovernor), config);
+        let result = layer
+            .process("POST", "/api/chat", Some("application/json"), r#"{"content": "#)
+            .unwrap();
+        assert!(!TorkLayer::should_block(&result));
+        assert_eq!(layer.counters().unparsed(), 1);
+    }
+
+    #[test]
+    fn test_tork_as_injected_governor() {
+        let layer = TorkLayer::with_governor(Arc::new(Tork::new()));
+        let result = layer
+            .process("POST", "/api/chat", Some("application/json"), r#"{"content": "SSN: 123-45-6789"}"#)
+            .unwrap();
+        assert_eq!(result.output, "SSN: [SSN_REDACTED]");
+    }
 }
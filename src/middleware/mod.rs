@@ -4,17 +4,42 @@
 //! - Actix Web
 //! - Axum
 //! - Rocket
+//!
+//! # Forwarding redacted bodies
+//!
+//! `process` only computes a [`GovernanceResult`]. To hand scrubbed input to
+//! downstream handlers, enable `rewrite_body` and substitute the output back
+//! into the body before forwarding:
+//!
+//! ```rust
+//! use tork_governance::middleware::{rewrite_content, MiddlewareConfig};
+//! use tork_governance::middleware::axum::TorkLayer;
+//!
+//! let config = MiddlewareConfig { rewrite_body: true, ..Default::default() };
+//! let layer = TorkLayer::with_config(config.clone());
+//! let body = r#"{"content":"SSN: 123-45-6789","user":"42"}"#;
+//!
+//! let forwarded = match layer.process("POST", "/api/chat", Some("application/json"), body) {
+//!     Some(result) => rewrite_content(body, &config, &result),
+//!     None => body.to_string(),
+//! };
+//! assert_eq!(forwarded, r#"{"content":"SSN: [SSN_REDACTED]","user":"42"}"#);
+//! ```
 
 pub mod actix;
 pub mod axum;
 pub mod rocket;
 
-use crate::{GovernanceResult, Tork};
+use crate::{DenyReason, GovernanceAction, GovernanceResult, Governor, RedactionSpan, Tork};
 use serde::{Deserialize, Serialize};
-use std::sync::{Arc, Mutex};
+use std::cmp::Reverse;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
 
 /// Configuration for middleware
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MiddlewareConfig {
     /// Paths that should be protected (default: ["/api/"])
     pub protected_paths: Vec<String>,
@@ -22,6 +47,63 @@ pub struct MiddlewareConfig {
     pub skip_paths: Vec<String>,
     /// Content field names to look for in JSON body
     pub content_fields: Vec<String>,
+    /// JSON paths to extract content from, e.g. `message.text` or
+    /// `messages[*].content` (default: []). Checked before `content_fields`.
+    pub content_paths: Vec<String>,
+    /// Substitute governed output back into the request body before it is
+    /// forwarded (default: false). See [`rewrite_content`].
+    pub rewrite_body: bool,
+    /// When the middleware answers a request itself instead of forwarding it
+    /// (default: `BlockOnDeny`). See [`middleware_response`].
+    pub respond_mode: RespondMode,
+    /// What to do with an in-scope body that isn't valid JSON (default:
+    /// `Skip`). Only applies when the body is parsed as JSON, see
+    /// [`extract_content_typed`].
+    pub invalid_json: InvalidJsonPolicy,
+    /// Whether governance decisions are acted on (default: `Enforce`)
+    pub enforcement: Enforcement,
+}
+
+/// When a middleware responds directly rather than calling the handler
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RespondMode {
+    /// Always forward to the handler, even when denied
+    Forward,
+    /// Respond 403 with a problem details body on `Deny`, otherwise forward
+    #[default]
+    BlockOnDeny,
+    /// Like `BlockOnDeny`, and also respond 200 with the redacted output on
+    /// `Redact` and `Escalate`
+    RespondRedacted,
+}
+
+/// How a middleware treats a body that fails to parse as JSON
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InvalidJsonPolicy {
+    /// Forward the request ungoverned, as [`MiddlewareOutcome::NoContent`]
+    #[default]
+    Skip,
+    /// Deny the request, with the raw body as the governed input
+    Deny,
+}
+
+/// Whether a middleware acts on governance decisions or only observes them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Enforcement {
+    /// Govern and record as usual, but forward the original content
+    ///
+    /// Results come back with `action` set to `Allow`, `output` equal to the
+    /// extracted content and `modified` false, so nothing is blocked, rewritten or answered
+    /// early. `pii`, `rationale` and the receipt (including its `action`)
+    /// still describe what enforcement would have done, for measuring
+    /// exposure before turning it on.
+    Monitor,
+    /// Act on the governance action
+    #[default]
+    Enforce,
 }
 
 impl Default for MiddlewareConfig {
@@ -37,6 +119,11 @@ impl Default for MiddlewareConfig {
                 "query".to_string(),
                 "input".to_string(),
             ],
+            content_paths: vec![],
+            rewrite_body: false,
+            respond_mode: RespondMode::default(),
+            invalid_json: InvalidJsonPolicy::default(),
+            enforcement: Enforcement::default(),
         }
     }
 }
@@ -49,20 +136,346 @@ pub fn create_shared_tork() -> SharedTork {
     Arc::new(Mutex::new(Tork::new()))
 }
 
-/// Extract content from JSON body
-pub fn extract_content(body: &str, config: &MiddlewareConfig) -> Option<String> {
-    let json: serde_json::Value = serde_json::from_str(body).ok()?;
+/// Lock a shared Tork instance, recovering it if a previous holder panicked
+///
+/// A panic while governing poisons the mutex, and `lock().unwrap()` would
+/// then fail every later request. Governance never leaves the config half
+/// updated, so the middlewares take the guard back from the poison error and
+/// keep serving; at worst the stats of the interrupted call are incomplete.
+pub fn lock_tork(tork: &SharedTork) -> MutexGuard<'_, Tork> {
+    tork.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Governor injected into a middleware, see [`Governor`]
+pub type SharedGovernor = Arc<dyn Governor + Send + Sync>;
+
+/// Governs through the lock, recovering it if poisoned as [`lock_tork`] does,
+/// so a [`SharedTork`] can be used as a [`SharedGovernor`]
+impl Governor for Mutex<Tork> {
+    fn govern(&self, input: &str) -> GovernanceResult {
+        Governor::govern(&*self.lock().unwrap_or_else(PoisonError::into_inner), input)
+    }
+
+    fn govern_unparsed(&self, body: &str) -> GovernanceResult {
+        self.lock().unwrap_or_else(PoisonError::into_inner).govern_unparsed(body)
+    }
+}
+
+/// Separator used when several content values are extracted from one body
+pub const CONTENT_SEPARATOR: &str = "\n";
+
+/// Why content could not be extracted from a request body
+#[derive(Debug)]
+pub enum ExtractError {
+    /// The body is not valid JSON
+    InvalidJson(serde_json::Error),
+}
+
+impl fmt::Display for ExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExtractError::InvalidJson(e) => write!(f, "request body is not valid JSON: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ExtractError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ExtractError::InvalidJson(e) => Some(e),
+        }
+    }
+}
+
+/// A single step in a content path
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PathSegment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+/// Parse a dotted/bracketed path such as `messages[*].content`
+fn parse_content_path(path: &str) -> Vec<PathSegment> {
+    let mut segments = Vec::new();
+    for part in path.split('.').filter(|p| !p.is_empty()) {
+        let (key, mut rest) = match part.find('[') {
+            Some(idx) => (&part[..idx], &part[idx..]),
+            None => (part, ""),
+        };
+        if key == "*" {
+            segments.push(PathSegment::Wildcard);
+        } else if !key.is_empty() {
+            segments.push(PathSegment::Key(key.to_string()));
+        }
+        while let Some(end) = rest.find(']') {
+            let index = &rest[1..end];
+            if index == "*" {
+                segments.push(PathSegment::Wildcard);
+            } else if let Ok(i) = index.parse() {
+                segments.push(PathSegment::Index(i));
+            } else {
+                segments.push(PathSegment::Key(index.trim_matches(['"', '\'']).to_string()));
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+    segments
+}
+
+/// Escape a key for use in a JSON pointer (RFC 6901)
+fn escape_pointer(key: &str) -> String {
+    key.replace('~', "~0").replace('/', "~1")
+}
+
+/// Collect every non-empty string reachable through `segments`, with its JSON pointer
+fn collect_path_strings(
+    value: &serde_json::Value,
+    segments: &[PathSegment],
+    pointer: String,
+    out: &mut Vec<(String, String)>,
+) {
+    let Some((segment, rest)) = segments.split_first() else {
+        if let serde_json::Value::String(s) = value {
+            if !s.is_empty() {
+                out.push((pointer, s.clone()));
+            }
+        }
+        return;
+    };
+
+    match (segment, value) {
+        (PathSegment::Key(key), serde_json::Value::Object(map)) => {
+            if let Some(child) = map.get(key) {
+                collect_path_strings(child, rest, format!("{}/{}", pointer, escape_pointer(key)), out);
+            }
+        }
+        (PathSegment::Index(i), serde_json::Value::Array(items)) => {
+            if let Some(child) = items.get(*i) {
+                collect_path_strings(child, rest, format!("{}/{}", pointer, i), out);
+            }
+        }
+        (PathSegment::Wildcard, serde_json::Value::Array(items)) => {
+            for (i, child) in items.iter().enumerate() {
+                collect_path_strings(child, rest, format!("{}/{}", pointer, i), out);
+            }
+        }
+        (PathSegment::Wildcard, serde_json::Value::Object(map)) => {
+            for (key, child) in map {
+                collect_path_strings(child, rest, format!("{}/{}", pointer, escape_pointer(key)), out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Locate content values in a parsed body as `(json_pointer, value)` pairs
+///
+/// `content_paths` are tried first and every match is returned; if none match,
+/// the first non-empty top-level `content_fields` entry is used.
+fn locate_content(json: &serde_json::Value, config: &MiddlewareConfig) -> Vec<(String, String)> {
+    let mut found = Vec::new();
+    for path in &config.content_paths {
+        collect_path_strings(json, &parse_content_path(path), String::new(), &mut found);
+    }
+    if !found.is_empty() {
+        return found;
+    }
 
     if let serde_json::Value::Object(map) = json {
         for field in &config.content_fields {
             if let Some(serde_json::Value::String(s)) = map.get(field) {
                 if !s.is_empty() {
-                    return Some(s.clone());
+                    return vec![(format!("/{}", escape_pointer(field)), s.clone())];
                 }
             }
         }
     }
-    None
+    found
+}
+
+/// Extract every content value from a JSON body
+///
+/// Returns an empty list for a body that isn't JSON; use [`extract_content`]
+/// to tell that apart from a body without content.
+pub fn extract_content_values(body: &str, config: &MiddlewareConfig) -> Vec<String> {
+    match serde_json::from_str::<serde_json::Value>(body) {
+        Ok(json) => locate_content(&json, config).into_iter().map(|(_, s)| s).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Extract content from JSON body
+///
+/// When several values match (e.g. `messages[*].content`), they are joined
+/// with [`CONTENT_SEPARATOR`]. Returns `Ok(None)` for valid JSON without any
+/// configured field, and [`ExtractError::InvalidJson`] if the body doesn't
+/// parse.
+pub fn extract_content(body: &str, config: &MiddlewareConfig) -> Result<Option<String>, ExtractError> {
+    let json: serde_json::Value = serde_json::from_str(body).map_err(ExtractError::InvalidJson)?;
+    let values: Vec<String> = locate_content(&json, config).into_iter().map(|(_, s)| s).collect();
+    if values.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(values.join(CONTENT_SEPARATOR)))
+    }
+}
+
+/// Extract content from a body according to its `Content-Type` header
+///
+/// `text/plain` bodies are governed whole, and `application/x-www-form-urlencoded`
+/// bodies are decoded and searched for the first non-empty `content_fields`
+/// entry. Any other type, or a missing header, is parsed as JSON as in
+/// [`extract_content`], and only then can this fail.
+pub fn extract_content_typed(
+    content_type: Option<&str>,
+    body: &str,
+    config: &MiddlewareConfig,
+) -> Result<Option<String>, ExtractError> {
+    let media_type = content_type
+        .and_then(|ct| ct.split(';').next())
+        .map(|mt| mt.trim().to_ascii_lowercase());
+    match media_type.as_deref() {
+        Some("text/plain") => Ok((!body.is_empty()).then(|| body.to_string())),
+        Some("application/x-www-form-urlencoded") => {
+            let pairs: Vec<(String, String)> = form_urlencoded::parse(body.as_bytes())
+                .into_owned()
+                .collect();
+            Ok(config.content_fields.iter().find_map(|field| {
+                pairs
+                    .iter()
+                    .find(|(key, value)| key == field && !value.is_empty())
+                    .map(|(_, value)| value.clone())
+            }))
+        }
+        _ => extract_content(body, config),
+    }
+}
+
+/// Substitute a governance result's output back into the JSON body it was extracted from
+///
+/// The output replaces the same field(s) `extract_content` read, leaving the
+/// rest of the payload untouched. When several fields were extracted, the
+/// output is split back along [`CONTENT_SEPARATOR`]; if a redaction spanned two
+/// fields and the split no longer lines up, each field instead has its matched
+/// PII values replaced with their placeholders.
+///
+/// Returns `body` unchanged when `config.rewrite_body` is false, when the body
+/// isn't JSON, or when governance didn't modify the content. Use
+/// [`rewrite_content_typed`] for `text/plain` and form-encoded bodies.
+pub fn rewrite_content(body: &str, config: &MiddlewareConfig, result: &GovernanceResult) -> String {
+    if !config.rewrite_body {
+        return body.to_string();
+    }
+    let Ok(mut json) = serde_json::from_str::<serde_json::Value>(body) else {
+        return body.to_string();
+    };
+    let located = locate_content(&json, config);
+    if located.is_empty() {
+        return body.to_string();
+    }
+
+    let original: Vec<&str> = located.iter().map(|(_, s)| s.as_str()).collect();
+    if original.join(CONTENT_SEPARATOR) == result.output {
+        return body.to_string();
+    }
+
+    let replacements: Vec<String> = if located.len() == 1 {
+        vec![result.output.clone()]
+    } else {
+        split_output(&original, &result.output)
+            .unwrap_or_else(|| original.iter().map(|value| redact_values(value, result)).collect())
+    };
+
+    for ((pointer, _), replacement) in located.iter().zip(replacements) {
+        if let Some(target) = json.pointer_mut(pointer) {
+            *target = serde_json::Value::String(replacement);
+        }
+    }
+    serde_json::to_string(&json).unwrap_or_else(|_| body.to_string())
+}
+
+/// Substitute a governance result's output back into a body according to
+/// its `Content-Type` header
+///
+/// The counterpart of [`extract_content_typed`]: a `text/plain` body is
+/// replaced by the output, and in an `application/x-www-form-urlencoded` body
+/// the extracted field takes the output while every other field has the
+/// matched PII values replaced with their placeholders. Any other type is
+/// rewritten as JSON by [`rewrite_content`].
+pub fn rewrite_content_typed(
+    content_type: Option<&str>,
+    body: &str,
+    config: &MiddlewareConfig,
+    result: &GovernanceResult,
+) -> String {
+    if !config.rewrite_body {
+        return body.to_string();
+    }
+    let media_type = content_type
+        .and_then(|ct| ct.split(';').next())
+        .map(|mt| mt.trim().to_ascii_lowercase());
+    match media_type.as_deref() {
+        Some("text/plain") if !body.is_empty() => result.output.clone(),
+        Some("text/plain") => body.to_string(),
+        Some("application/x-www-form-urlencoded") => {
+            let pairs: Vec<(String, String)> = form_urlencoded::parse(body.as_bytes())
+                .into_owned()
+                .collect();
+            let Some(extracted) = config.content_fields.iter().find_map(|field| {
+                pairs
+                    .iter()
+                    .position(|(key, value)| key == field && !value.is_empty())
+            }) else {
+                return body.to_string();
+            };
+            if pairs[extracted].1 == result.output {
+                return body.to_string();
+            }
+            let mut form = form_urlencoded::Serializer::new(String::new());
+            for (i, (key, value)) in pairs.iter().enumerate() {
+                if i == extracted {
+                    form.append_pair(key, &result.output);
+                } else {
+                    form.append_pair(key, &redact_values(value, result));
+                }
+            }
+            form.finish()
+        }
+        _ => rewrite_content(body, config, result),
+    }
+}
+
+/// `value` with every text redacted in `result` replaced as it was there
+///
+/// The replacements come from the result's redaction spans, so they follow
+/// the config's redaction style, custom and indexed placeholders and marks.
+/// Longer texts go first, so a value inside a redacted sentence doesn't
+/// break it up.
+fn redact_values(value: &str, result: &GovernanceResult) -> String {
+    let mut spans: Vec<&RedactionSpan> = result.pii.spans.iter().collect();
+    spans.sort_by_key(|span| Reverse(span.original_value.len()));
+    spans
+        .iter()
+        .fold(value.to_string(), |acc, span| acc.replace(&span.original_value, &span.replacement))
+}
+
+/// Split joined output back into one piece per original value, by line count
+fn split_output(original: &[&str], output: &str) -> Option<Vec<String>> {
+    let lines: Vec<&str> = output.split(CONTENT_SEPARATOR).collect();
+    let expected: usize = original.iter().map(|s| s.split(CONTENT_SEPARATOR).count()).sum();
+    if lines.len() != expected {
+        return None;
+    }
+
+    let mut pieces = Vec::with_capacity(original.len());
+    let mut offset = 0;
+    for value in original {
+        let n = value.split(CONTENT_SEPARATOR).count();
+        pieces.push(lines[offset..offset + n].join(CONTENT_SEPARATOR));
+        offset += n;
+    }
+    Some(pieces)
 }
 
 /// Check if a path should be skipped
@@ -85,20 +498,563 @@ pub fn should_protect_path(path: &str, config: &MiddlewareConfig) -> bool {
     false
 }
 
+/// Why a middleware let a request through without governing it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// Only `POST`, `PUT` and `PATCH` bodies are governed
+    Method,
+    /// The path matches `skip_paths`
+    SkipPath,
+    /// The path matches none of `protected_paths`
+    UnprotectedPath,
+}
+
+/// What a middleware did with a request, from `process_verbose`
+#[derive(Debug, Clone)]
+pub enum MiddlewareOutcome {
+    /// The request is out of scope
+    Skipped(SkipReason),
+    /// The request is in scope, but no content could be extracted from its
+    /// body (no configured field present, or not JSON under
+    /// [`InvalidJsonPolicy::Skip`])
+    NoContent,
+    /// The extracted content was governed
+    Governed(Box<GovernanceResult>),
+}
+
+impl MiddlewareOutcome {
+    /// The governance result, if the content was governed
+    pub fn into_result(self) -> Option<GovernanceResult> {
+        match self {
+            MiddlewareOutcome::Governed(result) => Some(*result),
+            _ => None,
+        }
+    }
+}
+
+/// Requests a middleware let through ungoverned, shared by its clones
+#[derive(Debug, Default)]
+pub struct MiddlewareCounters {
+    skipped: AtomicU64,
+    unparsed: AtomicU64,
+}
+
+impl MiddlewareCounters {
+    /// Requests out of scope by method or path
+    pub fn skipped(&self) -> u64 {
+        self.skipped.load(Ordering::Relaxed)
+    }
+
+    /// In-scope requests whose body yielded no content
+    pub fn unparsed(&self) -> u64 {
+        self.unparsed.load(Ordering::Relaxed)
+    }
+}
+
+/// Scope-check, extract and govern a request, counting what was let through
+fn process_request(
+    governor: &dyn Governor,
+    config: &MiddlewareConfig,
+    counters: &MiddlewareCounters,
+    method: &str,
+    path: &str,
+    content_type: Option<&str>,
+    body: &str,
+) -> MiddlewareOutcome {
+    // Only process POST, PUT, PATCH
+    let skip = if !["POST", "PUT", "PATCH"].contains(&method) {
+        Some(SkipReason::Method)
+    } else if should_skip_path(path, config) {
+        Some(SkipReason::SkipPath)
+    } else if !should_protect_path(path, config) {
+        Some(SkipReason::UnprotectedPath)
+    } else {
+        None
+    };
+    if let Some(reason) = skip {
+        counters.skipped.fetch_add(1, Ordering::Relaxed);
+        return MiddlewareOutcome::Skipped(reason);
+    }
+
+    let governed = |mut result: GovernanceResult, original: &str| {
+        if config.enforcement == Enforcement::Monitor {
+            result.action = GovernanceAction::Allow;
+            result.output = original.to_string();
+            result.modified = false;
+        }
+        MiddlewareOutcome::Governed(Box::new(result))
+    };
+    match extract_content_typed(content_type, body, config) {
+        Ok(Some(content)) => governed(governor.govern(&content), &content),
+        Ok(None) => {
+            counters.unparsed.fetch_add(1, Ordering::Relaxed);
+            MiddlewareOutcome::NoContent
+        }
+        Err(ExtractError::InvalidJson(_)) => {
+            counters.unparsed.fetch_add(1, Ordering::Relaxed);
+            match config.invalid_json {
+                InvalidJsonPolicy::Skip => MiddlewareOutcome::NoContent,
+                InvalidJsonPolicy::Deny => governed(governor.govern_unparsed(body), body),
+            }
+        }
+    }
+}
+
+/// Status and JSON body to answer with under `mode`, or `None` to forward
+///
+/// The status is [`GovernanceResult::recommended_status`]. Denials get a 403
+/// [`ErrorResponse::to_problem_json`] body; redacted responses get a
+/// `{"output", "receipt_id", "action"}` body, 200 or 202 when escalated.
+/// Framework glue only has to turn the pair into its response type.
+pub fn middleware_response(
+    result: &GovernanceResult,
+    mode: RespondMode,
+) -> Option<(u16, serde_json::Value)> {
+    let status = result.recommended_status();
+    match (mode, result.action) {
+        (RespondMode::Forward, _) => None,
+        (_, GovernanceAction::Deny) => Some((status, ErrorResponse::from_result(result).to_problem_json(status))),
+        (RespondMode::RespondRedacted, GovernanceAction::Redact | GovernanceAction::Escalate) => {
+            Some((
+                status,
+                serde_json::json!({
+                    "output": result.output,
+                    "receipt_id": result.receipt.receipt_id,
+                    "action": result.action,
+                }),
+            ))
+        }
+        _ => None,
+    }
+}
+
 /// Error response structure
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ErrorResponse {
+    /// Human-readable message
     pub error: String,
+    /// Stable machine-readable reason, one of the `ERROR_CODE_*` constants
+    #[serde(default)]
+    pub code: String,
     pub receipt_id: String,
     pub pii_types: Vec<String>,
+    /// Why the policy decided as it did, see [`GovernanceResult::rationale`]
+    #[serde(default)]
+    pub rationale: Vec<String>,
 }
 
+/// [`ErrorResponse::code`] when a denylist term was present
+pub const ERROR_CODE_DENYLIST: &str = "denylist.match";
+
+/// [`ErrorResponse::code`] when detected PII resolved to `Deny`
+pub const ERROR_CODE_PII_DENIED: &str = "pii.denied";
+
+/// [`ErrorResponse::code`] when only a match below `min_confidence` led to
+/// `Deny`, see [`TorkConfig::uncertain_action`](crate::TorkConfig::uncertain_action)
+pub const ERROR_CODE_PII_UNCERTAIN: &str = "pii.uncertain";
+
+/// [`ErrorResponse::code`] when matches covered more of the input than
+/// [`TorkConfig::max_redaction_ratio`](crate::TorkConfig::max_redaction_ratio)
+/// and `budget_action` is `Deny`
+pub const ERROR_CODE_REDACTION_BUDGET: &str = "redaction.budget_exceeded";
+
+/// [`ErrorResponse::code`] for any other denial, such as an unparsable body
+/// under [`InvalidJsonPolicy::Deny`]
+pub const ERROR_CODE_DENIED: &str = "governance.denied";
+
+/// Content type for RFC 7807 problem details bodies
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
+/// RFC 7807 `type` URI identifying a governance block
+pub const PROBLEM_TYPE_GOVERNANCE_BLOCKED: &str = "urn:tork:problem:governance-blocked";
+
 impl ErrorResponse {
     pub fn from_result(result: &GovernanceResult) -> Self {
+        let code = match result.deny_reason {
+            Some(DenyReason::Denylist) => ERROR_CODE_DENYLIST,
+            Some(DenyReason::Pii) => ERROR_CODE_PII_DENIED,
+            Some(DenyReason::UncertainPii) => ERROR_CODE_PII_UNCERTAIN,
+            Some(DenyReason::RedactionBudget) => ERROR_CODE_REDACTION_BUDGET,
+            None => ERROR_CODE_DENIED,
+        };
         Self {
             error: "Request blocked by governance policy".to_string(),
+            code: code.to_string(),
             receipt_id: result.receipt.receipt_id.clone(),
-            pii_types: result.pii.types.iter().map(|t| format!("{:?}", t).to_lowercase()).collect(),
+            pii_types: result.pii.types.iter().map(ToString::to_string).collect(),
+            rationale: result.rationale.clone(),
         }
     }
+
+    /// Render as an RFC 7807 problem details object
+    ///
+    /// `error` becomes `title`; `code`, `receipt_id`, `pii_types` and
+    /// `rationale` are carried as extension members. Serve with
+    /// [`PROBLEM_JSON_CONTENT_TYPE`].
+    pub fn to_problem_json(&self, status: u16) -> serde_json::Value {
+        let detail = if self.pii_types.is_empty() {
+            self.error.clone()
+        } else {
+            format!("{}: detected {}", self.error, self.pii_types.join(", "))
+        };
+        serde_json::json!({
+            "type": PROBLEM_TYPE_GOVERNANCE_BLOCKED,
+            "title": self.error,
+            "status": status,
+            "detail": detail,
+            "code": self.code,
+            "receipt_id": self.receipt_id,
+            "pii_types": self.pii_types,
+            "rationale": self.rationale,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_paths(paths: &[&str]) -> MiddlewareConfig {
+        MiddlewareConfig {
+            content_paths: paths.iter().map(|p| p.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_extract_nested_object() {
+        let config = config_with_paths(&["message.text"]);
+        let body = r#"{"message": {"text": "SSN: 123-45-6789", "id": 1}}"#;
+        assert_eq!(extract_content(body, &config).unwrap(), Some("SSN: 123-45-6789".to_string()));
+    }
+
+    #[test]
+    fn test_extract_array_wildcard() {
+        let config = config_with_paths(&["messages[*].content"]);
+        let body = r#"{"messages": [
+            {"role": "system", "content": "be nice"},
+            {"role": "user", "content": "my email is a@b.com"}
+        ]}"#;
+        assert_eq!(
+            extract_content_values(body, &config),
+            vec!["be nice".to_string(), "my email is a@b.com".to_string()]
+        );
+        assert_eq!(
+            extract_content(body, &config).unwrap(),
+            Some("be nice\nmy email is a@b.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_extract_array_index() {
+        let config = config_with_paths(&["messages[1].content"]);
+        let body = r#"{"messages": [{"content": "first"}, {"content": "second"}]}"#;
+        assert_eq!(extract_content(body, &config).unwrap(), Some("second".to_string()));
+    }
+
+    #[test]
+    fn test_extract_missing_path_falls_back_to_fields() {
+        let config = config_with_paths(&["message.text"]);
+        let body = r#"{"prompt": "hello"}"#;
+        assert_eq!(extract_content(body, &config).unwrap(), Some("hello".to_string()));
+    }
+
+    #[test]
+    fn test_extract_missing_path_without_fields() {
+        let config = config_with_paths(&["messages[*].content", "a.b.c"]);
+        let body = r#"{"messages": "not an array", "a": {"b": 3}}"#;
+        assert_eq!(extract_content(body, &config).unwrap(), None);
+    }
+
+    #[test]
+    fn test_extract_malformed_json() {
+        let config = MiddlewareConfig::default();
+        let err = extract_content(r#"{"content": "SSN 123-45-6789""#, &config).unwrap_err();
+        assert!(matches!(err, ExtractError::InvalidJson(_)));
+        assert!(err.to_string().starts_with("request body is not valid JSON"));
+    }
+
+    #[test]
+    fn test_extract_valid_json_without_content() {
+        let config = MiddlewareConfig::default();
+        assert_eq!(extract_content("{}", &config).unwrap(), None);
+        assert_eq!(extract_content(r#"{"content": ""}"#, &config).unwrap(), None);
+    }
+
+    #[test]
+    fn test_extract_valid_json_with_content() {
+        let config = MiddlewareConfig::default();
+        let body = r#"{"user": "42", "prompt": "mail a@b.com"}"#;
+        assert_eq!(extract_content(body, &config).unwrap(), Some("mail a@b.com".to_string()));
+    }
+
+    #[test]
+    fn test_extract_plain_text_body() {
+        let config = MiddlewareConfig::default();
+        let body = "My SSN is 123-45-6789";
+        assert_eq!(
+            extract_content_typed(Some("text/plain; charset=utf-8"), body, &config).unwrap(),
+            Some(body.to_string())
+        );
+        assert!(matches!(
+            extract_content_typed(None, body, &config),
+            Err(ExtractError::InvalidJson(_))
+        ));
+    }
+
+    #[test]
+    fn test_extract_urlencoded_body() {
+        let config = MiddlewareConfig::default();
+        let body = "user=42&prompt=mail+me+at+a%40b.com";
+        assert_eq!(
+            extract_content_typed(Some("application/x-www-form-urlencoded"), body, &config).unwrap(),
+            Some("mail me at a@b.com".to_string())
+        );
+    }
+
+    #[test]
+    fn test_rewrite_nested_field() {
+        let config = MiddlewareConfig {
+            content_paths: vec!["message.text".to_string()],
+            rewrite_body: true,
+            ..Default::default()
+        };
+        let body = r#"{"model":"gpt","message":{"text":"SSN: 123-45-6789","id":7},"stream":false}"#;
+        let result = Tork::new().govern(&extract_content(body, &config).unwrap().unwrap());
+
+        let rewritten: serde_json::Value =
+            serde_json::from_str(&rewrite_content(body, &config, &result)).unwrap();
+        assert_eq!(rewritten["message"]["text"], "SSN: [SSN_REDACTED]");
+        assert_eq!(rewritten["message"]["id"], 7);
+        assert_eq!(rewritten["model"], "gpt");
+        assert_eq!(rewritten["stream"], false);
+    }
+
+    #[test]
+    fn test_rewrite_multiple_fields() {
+        let config = MiddlewareConfig {
+            content_paths: vec!["messages[*].content".to_string()],
+            rewrite_body: true,
+            ..Default::default()
+        };
+        let body = r#"{"messages":[{"role":"system","content":"be\nnice"},{"role":"user","content":"mail a@b.com"}]}"#;
+        let result = Tork::new().govern(&extract_content(body, &config).unwrap().unwrap());
+
+        let rewritten: serde_json::Value =
+            serde_json::from_str(&rewrite_content(body, &config, &result)).unwrap();
+        assert_eq!(rewritten["messages"][0]["content"], "be\nnice");
+        assert_eq!(rewritten["messages"][0]["role"], "system");
+        assert_eq!(rewritten["messages"][1]["content"], "mail [EMAIL_REDACTED]");
+    }
+
+    #[test]
+    fn test_rewrite_plain_text_body() {
+        let config = MiddlewareConfig {
+            rewrite_body: true,
+            ..Default::default()
+        };
+        let content_type = Some("text/plain; charset=utf-8");
+        let body = "My SSN is 123-45-6789";
+        let content = extract_content_typed(content_type, body, &config).unwrap().unwrap();
+        let result = Tork::new().govern(&content);
+
+        assert_eq!(
+            rewrite_content_typed(content_type, body, &config, &result),
+            "My SSN is [SSN_REDACTED]"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_urlencoded_body() {
+        let config = MiddlewareConfig {
+            rewrite_body: true,
+            ..Default::default()
+        };
+        let content_type = Some("application/x-www-form-urlencoded");
+        let body = "user=42&prompt=mail+me+at+a%40b.com&cc=a%40b.com";
+        let content = extract_content_typed(content_type, body, &config).unwrap().unwrap();
+        let result = Tork::new().govern(&content);
+
+        let rewritten = rewrite_content_typed(content_type, body, &config, &result);
+        let pairs: Vec<(String, String)> = form_urlencoded::parse(rewritten.as_bytes()).into_owned().collect();
+        assert_eq!(
+            pairs,
+            [
+                ("user".to_string(), "42".to_string()),
+                ("prompt".to_string(), "mail me at [EMAIL_REDACTED]".to_string()),
+                ("cc".to_string(), "[EMAIL_REDACTED]".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rewrite_other_fields_follow_redaction_style() {
+        let config = MiddlewareConfig {
+            rewrite_body: true,
+            ..Default::default()
+        };
+        let tork = Tork::with_config(crate::TorkConfig {
+            redaction_style: crate::RedactionStyle::Indexed,
+            ..Default::default()
+        });
+        let content_type = Some("application/x-www-form-urlencoded");
+        let body = "prompt=mail+a%40b.com+or+c%40d.com&cc=c%40d.com";
+        let content = extract_content_typed(content_type, body, &config).unwrap().unwrap();
+        let result = tork.govern(&content);
+
+        let rewritten = rewrite_content_typed(content_type, body, &config, &result);
+        let pairs: Vec<(String, String)> = form_urlencoded::parse(rewritten.as_bytes()).into_owned().collect();
+        assert_eq!(pairs[0].1, "mail [EMAIL_REDACTED:1] or [EMAIL_REDACTED:2]");
+        assert_eq!(pairs[1].1, "[EMAIL_REDACTED:2]");
+    }
+
+    #[test]
+    fn test_rewrite_disabled_returns_body() {
+        let config = MiddlewareConfig::default();
+        let body = r#"{"content":"SSN: 123-45-6789"}"#;
+        let result = Tork::new().govern("SSN: 123-45-6789");
+        assert_eq!(rewrite_content(body, &config, &result), body);
+    }
+
+    #[test]
+    fn test_problem_json_members() {
+        let config = crate::TorkConfig {
+            default_action: crate::GovernanceAction::Deny,
+            ..Default::default()
+        };
+        let result = Tork::with_config(config).govern("SSN: 123-45-6789");
+        let error = ErrorResponse::from_result(&result);
+        let problem = error.to_problem_json(403);
+
+        for member in ["type", "title", "status", "detail"] {
+            assert!(problem.get(member).is_some(), "missing {}", member);
+        }
+        assert_eq!(problem["status"], 403);
+        assert_eq!(problem["title"], error.error);
+        assert_eq!(problem["receipt_id"], result.receipt.receipt_id);
+
+        let pii_types: Vec<String> = serde_json::from_value(problem["pii_types"].clone()).unwrap();
+        assert_eq!(pii_types, error.pii_types);
+        assert_eq!(PROBLEM_JSON_CONTENT_TYPE, "application/problem+json");
+    }
+
+    #[test]
+    fn test_error_response_uses_snake_case_types() {
+        let config = crate::TorkConfig {
+            default_action: GovernanceAction::Deny,
+            ..Default::default()
+        };
+        let result = Tork::with_config(config).govern("card 4111 1111 1111 1111");
+        assert_eq!(ErrorResponse::from_result(&result).pii_types, vec!["credit_card"]);
+    }
+
+    #[test]
+    fn test_error_code_distinguishes_pii_and_denylist() {
+        let config = crate::TorkConfig {
+            default_action: GovernanceAction::Deny,
+            denylist: vec!["confidential".to_string()],
+            ..Default::default()
+        };
+        let tork = Tork::with_config(config);
+
+        let pii = ErrorResponse::from_result(&tork.govern("SSN: 123-45-6789"));
+        assert_eq!(pii.code, ERROR_CODE_PII_DENIED);
+        assert_eq!(pii.rationale, vec!["ssn matched and default_action=deny"]);
+
+        let denylisted = ErrorResponse::from_result(&tork.govern("This is confidential"));
+        assert_eq!(denylisted.code, ERROR_CODE_DENYLIST);
+        assert_eq!(denylisted.rationale, vec!["denylist term 'confidential' present"]);
+        assert_ne!(pii.code, denylisted.code);
+
+        let problem = denylisted.to_problem_json(403);
+        assert_eq!(problem["code"], "denylist.match");
+        assert_eq!(problem["rationale"][0], "denylist term 'confidential' present");
+    }
+
+    #[test]
+    fn test_error_code_for_budget_denial() {
+        let config = crate::TorkConfig {
+            max_redaction_ratio: Some(0.5),
+            budget_action: GovernanceAction::Deny,
+            ..Default::default()
+        };
+        let result = Tork::with_config(config).govern("123-45-6789");
+        assert!(result.pii.has_pii);
+        assert_eq!(result.deny_reason, Some(DenyReason::RedactionBudget));
+        assert_eq!(ErrorResponse::from_result(&result).code, ERROR_CODE_REDACTION_BUDGET);
+    }
+
+    #[test]
+    fn test_error_code_for_other_denials() {
+        let tork = Tork::new();
+        let pii = tork.detect("");
+        let result = tork.apply_action("not json", &pii, GovernanceAction::Deny);
+        assert_eq!(ErrorResponse::from_result(&result).code, ERROR_CODE_DENIED);
+    }
+
+    fn governed(action: GovernanceAction) -> GovernanceResult {
+        let config = crate::TorkConfig {
+            default_action: action,
+            ..Default::default()
+        };
+        Tork::with_config(config).govern("SSN: 123-45-6789")
+    }
+
+    #[test]
+    fn test_respond_mode_forward() {
+        for action in [GovernanceAction::Redact, GovernanceAction::Deny] {
+            assert_eq!(middleware_response(&governed(action), RespondMode::Forward), None);
+        }
+    }
+
+    #[test]
+    fn test_respond_mode_block_on_deny() {
+        let redacted = governed(GovernanceAction::Redact);
+        assert_eq!(middleware_response(&redacted, RespondMode::BlockOnDeny), None);
+
+        let denied = governed(GovernanceAction::Deny);
+        let (status, body) = middleware_response(&denied, RespondMode::BlockOnDeny).unwrap();
+        assert_eq!(status, 403);
+        assert_eq!(body["type"], PROBLEM_TYPE_GOVERNANCE_BLOCKED);
+        assert_eq!(body["receipt_id"], denied.receipt.receipt_id);
+    }
+
+    #[test]
+    fn test_respond_mode_respond_redacted() {
+        let redacted = governed(GovernanceAction::Redact);
+        let (status, body) = middleware_response(&redacted, RespondMode::RespondRedacted).unwrap();
+        assert_eq!(status, 200);
+        assert_eq!(body["output"], "SSN: [SSN_REDACTED]");
+        assert_eq!(body["receipt_id"], redacted.receipt.receipt_id);
+        assert_eq!(body["action"], "redact");
+
+        let denied = governed(GovernanceAction::Deny);
+        let (status, _) = middleware_response(&denied, RespondMode::RespondRedacted).unwrap();
+        assert_eq!(status, 403);
+
+        let escalated = governed(GovernanceAction::Escalate);
+        let (status, body) = middleware_response(&escalated, RespondMode::RespondRedacted).unwrap();
+        assert_eq!(status, 202);
+        assert_eq!(body["action"], "escalate");
+
+        let clean = Tork::new().govern("hello");
+        assert_eq!(middleware_response(&clean, RespondMode::RespondRedacted), None);
+    }
+
+    #[test]
+    fn test_parse_content_path() {
+        assert_eq!(
+            parse_content_path("messages[*].content"),
+            vec![
+                PathSegment::Key("messages".to_string()),
+                PathSegment::Wildcard,
+                PathSegment::Key("content".to_string()),
+            ]
+        );
+        assert_eq!(
+            parse_content_path("a[0][2]"),
+            vec![PathSegment::Key("a".to_string()), PathSegment::Index(0), PathSegment::Index(2)]
+        );
+    }
 }
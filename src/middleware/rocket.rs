@@ -28,7 +28,9 @@
 //! }
 //! ```
 
-use super::{extract_content, should_protect_path, should_skip_path, ErrorResponse, MiddlewareConfig, SharedTork};
+use super::{
+    process_request, ErrorResponse, MiddlewareConfig, MiddlewareCounters, MiddlewareOutcome, SharedGovernor, SharedTork,
+};
 use crate::{GovernanceAction, GovernanceResult, Tork};
 use std::sync::{Arc, Mutex};
 
@@ -37,38 +39,52 @@ pub type TorkRocketResult = GovernanceResult;
 
 /// Rocket fairing for Tork governance
 pub struct TorkFairing {
-    tork: SharedTork,
+    governor: SharedGovernor,
+    tork: Option<SharedTork>,
     config: MiddlewareConfig,
+    counters: Arc<MiddlewareCounters>,
 }
 
 impl TorkFairing {
     /// Create new fairing with default configuration
     pub fn new() -> Self {
-        Self {
-            tork: Arc::new(Mutex::new(Tork::new())),
-            config: MiddlewareConfig::default(),
-        }
+        Self::with_config(MiddlewareConfig::default())
     }
 
     /// Create new fairing with custom configuration
     pub fn with_config(config: MiddlewareConfig) -> Self {
-        Self {
-            tork: Arc::new(Mutex::new(Tork::new())),
-            config,
-        }
+        Self::with_tork_and_config(Arc::new(Mutex::new(Tork::new())), config)
     }
 
     /// Create new fairing with existing Tork instance
     pub fn with_tork(tork: SharedTork) -> Self {
-        Self {
-            tork,
-            config: MiddlewareConfig::default(),
-        }
+        Self::with_tork_and_config(tork, MiddlewareConfig::default())
     }
 
     /// Create new fairing with custom Tork and config
     pub fn with_tork_and_config(tork: SharedTork, config: MiddlewareConfig) -> Self {
-        Self { tork, config }
+        Self {
+            governor: tork.clone(),
+            tork: Some(tork),
+            config,
+            counters: Arc::default(),
+        }
+    }
+
+    /// Create new fairing governing through `governor`, e.g. a
+    /// [`NoOpGovernor`](crate::NoOpGovernor) in development
+    pub fn with_governor(governor: SharedGovernor) -> Self {
+        Self::with_governor_and_config(governor, MiddlewareConfig::default())
+    }
+
+    /// Create new fairing with custom governor and config
+    pub fn with_governor_and_config(governor: SharedGovernor, config: MiddlewareConfig) -> Self {
+        Self {
+            governor,
+            tork: None,
+            config,
+            counters: Arc::default(),
+        }
     }
 
     /// Get reference to config
@@ -77,32 +93,63 @@ impl TorkFairing {
     }
 
     /// Get reference to shared Tork
+    ///
+    /// # Panics
+    ///
+    /// If built with a governor rather than a Tork.
+    #[deprecated(note = "use `shared_tork`, which returns `None` when built with a governor")]
     pub fn tork(&self) -> &SharedTork {
-        &self.tork
+        self.shared_tork().expect("built with a governor, not a Tork")
     }
 
-    /// Process request body and return governance result
-    pub fn process(&self, method: &str, path: &str, body: &str) -> Option<GovernanceResult> {
-        // Only process POST, PUT, PATCH
-        if !["POST", "PUT", "PATCH"].contains(&method) {
-            return None;
-        }
+    /// Get reference to shared Tork, or `None` if built with a governor
+    pub fn shared_tork(&self) -> Option<&SharedTork> {
+        self.tork.as_ref()
+    }
 
-        // Check paths
-        if should_skip_path(path, &self.config) {
-            return None;
-        }
+    /// Get reference to the governor requests go through
+    pub fn governor(&self) -> &SharedGovernor {
+        &self.governor
+    }
 
-        if !should_protect_path(path, &self.config) {
-            return None;
-        }
+    /// Get the counts of requests let through ungoverned
+    pub fn counters(&self) -> &MiddlewareCounters {
+        &self.counters
+    }
 
-        // Extract content
-        let content = extract_content(body, &self.config)?;
+    /// Process request body and return governance result
+    ///
+    /// `content_type` is the request's `Content-Type` header, see
+    /// [`extract_content_typed`](super::extract_content_typed). Returns
+    /// `None` for skipped requests and bodies without content; use
+    /// [`process_verbose`](Self::process_verbose) to tell them apart.
+    pub fn process(
+        &self,
+        method: &str,
+        path: &str,
+        content_type: Option<&str>,
+        body: &str,
+    ) -> Option<GovernanceResult> {
+        self.process_verbose(method, path, content_type, body).into_result()
+    }
 
-        // Govern content
-        let mut tork = self.tork.lock().unwrap();
-        Some(tork.govern(&content))
+    /// Process request body, reporting why it wasn't governed if it wasn't
+    pub fn process_verbose(
+        &self,
+        method: &str,
+        path: &str,
+        content_type: Option<&str>,
+        body: &str,
+    ) -> MiddlewareOutcome {
+        process_request(
+            self.governor.as_ref(),
+            &self.config,
+            &self.counters,
+            method,
+            path,
+            content_type,
+            body,
+        )
     }
 
     /// Check if result should block the request
@@ -125,8 +172,10 @@ impl Default for TorkFairing {
 impl Clone for TorkFairing {
     fn clone(&self) -> Self {
         Self {
-            tork: Arc::clone(&self.tork),
+            governor: Arc::clone(&self.governor),
+            tork: self.tork.clone(),
             config: self.config.clone(),
+            counters: Arc::clone(&self.counters),
         }
     }
 }
@@ -252,6 +301,7 @@ mod tests {
         let result = fairing.process(
             "POST",
             "/api/chat",
+            Some("application/json"),
             r#"{"content": "Card: 4111-1111-1111-1111"}"#,
         );
 
@@ -260,9 +310,29 @@ mod tests {
         assert!(result.pii.has_pii);
     }
 
+    #[test]
+    fn test_process_recovers_from_poisoned_mutex() {
+        let fairing = TorkFairing::new();
+        let tork = fairing.shared_tork().unwrap().clone();
+        let _ = std::thread::spawn(move || {
+            let _guard = tork.lock().unwrap();
+            panic!("poison the shared Tork");
+        })
+        .join();
+        assert!(fairing.shared_tork().unwrap().is_poisoned());
+
+        let result = fairing.process(
+            "POST",
+            "/api/chat",
+            Some("application/json"),
+            r#"{"content": "My SSN is 123-45-6789"}"#,
+        );
+        assert!(result.unwrap().pii.has_pii);
+    }
+
     #[test]
     fn test_guard_methods() {
-        let mut tork = Tork::new();
+        let tork = Tork::new();
         let result = tork.govern("SSN: 123-45-6789");
         let guard = TorkGuard::new(Some(result));
 
@@ -0,0 +1,293 @@
+//! Person-name detection
+//!
+//! Names can't be matched reliably with a single pattern, so [`NameDetector`]
+//! combines two heuristics and reports each with a confidence that
+//! [`TorkConfig::min_confidence`](crate::TorkConfig::min_confidence) filters:
+//!
+//! - one or two capitalized words after a title (`Mr`, `Ms`, `Dr`, ...)
+//! - with the `name-gazetteer` feature, a known given name followed by a
+//!   capitalized word, scored higher when that word is a known surname
+//!
+//! At the default threshold only titled names are redacted; lower it to
+//! [`GAZETTEER_NAME_CONFIDENCE`] or below to also catch bare names.
+
+use crate::{Detector, PIIMatch, PIIType};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Confidence for a name introduced by a title (`Dr. Jane Smith`)
+pub const TITLED_NAME_CONFIDENCE: f32 = 0.7;
+/// Confidence for a known given name followed by a known surname (`Jane Smith`)
+pub const GAZETTEER_NAME_CONFIDENCE: f32 = 0.45;
+/// Confidence for a known given name followed by any capitalized word
+pub const GIVEN_NAME_CONFIDENCE: f32 = 0.3;
+
+/// Detector for [`PIIType::Name`]
+#[derive(Debug, Clone)]
+pub struct NameDetector {
+    titled: Regex,
+    #[cfg_attr(not(feature = "name-gazetteer"), allow(dead_code))]
+    capitalized: Regex,
+}
+
+impl NameDetector {
+    /// Create a detector; the patterns are compiled once and shared
+    pub fn new() -> Self {
+        static SHARED: OnceLock<NameDetector> = OnceLock::new();
+        SHARED.get_or_init(Self::compile).clone()
+    }
+
+    fn compile() -> Self {
+        NameDetector {
+            titled: Regex::new(
+                r"\b(?:Mr|Mrs|Ms|Miss|Mx|Dr|Prof)\.?\s+([A-Z][a-z]+(?:[-'][A-Z][a-z]+)?(?:\s+[A-Z][a-z]+(?:[-'][A-Z][a-z]+)?)?)\b",
+            )
+            .unwrap(),
+            capitalized: Regex::new(r"\b[A-Z][a-z]+(?:[-'][A-Z][a-z]+)?\b").unwrap(),
+        }
+    }
+
+    fn name_match(text: &str, start: usize, end: usize, confidence: f32) -> PIIMatch {
+        PIIMatch {
+            pii_type: PIIType::Name,
+            value: text[start..end].to_string(),
+            start_index: start,
+            end_index: end,
+            location: None,
+            confidence,
+            groups: Vec::new(),
+        }
+    }
+
+    #[cfg(feature = "name-gazetteer")]
+    fn gazetteer_matches(&self, text: &str, titled: &[PIIMatch]) -> Vec<PIIMatch> {
+        let tokens: Vec<_> = self.capitalized.find_iter(text).collect();
+        let mut matches = Vec::new();
+        let mut i = 0;
+        while i + 1 < tokens.len() {
+            let (first, second) = (tokens[i], tokens[i + 1]);
+            let adjacent = text[first.end()..second.start()]
+                .chars()
+                .all(char::is_whitespace);
+            let overlaps_titled = titled
+                .iter()
+                .any(|m| first.start() < m.end_index && m.start_index < second.end());
+
+            if adjacent && !overlaps_titled && is_given_name(first.as_str()) {
+                let confidence = if is_surname(second.as_str()) {
+                    GAZETTEER_NAME_CONFIDENCE
+                } else {
+                    GIVEN_NAME_CONFIDENCE
+                };
+                matches.push(Self::name_match(
+                    text,
+                    first.start(),
+                    second.end(),
+                    confidence,
+                ));
+                i += 2;
+            } else {
+                i += 1;
+            }
+        }
+        matches
+    }
+}
+
+impl Default for NameDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for NameDetector {
+    fn detect(&self, text: &str) -> Vec<PIIMatch> {
+        let titled: Vec<PIIMatch> = self
+            .titled
+            .captures_iter(text)
+            .filter_map(|caps| caps.get(1))
+            .map(|name| Self::name_match(text, name.start(), name.end(), TITLED_NAME_CONFIDENCE))
+            .collect();
+
+        #[cfg(feature = "name-gazetteer")]
+        {
+            let mut all = self.gazetteer_matches(text, &titled);
+            all.extend(titled);
+            all.sort_by_key(|m| m.start_index);
+            all
+        }
+        #[cfg(not(feature = "name-gazetteer"))]
+        titled
+    }
+}
+
+#[cfg(feature = "name-gazetteer")]
+fn is_given_name(token: &str) -> bool {
+    GIVEN_NAMES.contains(&token.to_lowercase().as_str())
+}
+
+#[cfg(feature = "name-gazetteer")]
+fn is_surname(token: &str) -> bool {
+    SURNAMES.contains(&token.to_lowercase().as_str())
+}
+
+/// Common given names (lowercase)
+#[cfg(feature = "name-gazetteer")]
+const GIVEN_NAMES: &[&str] = &[
+    "aaron",
+    "adam",
+    "aisha",
+    "alex",
+    "alice",
+    "amanda",
+    "amy",
+    "ana",
+    "andrew",
+    "angela",
+    "anna",
+    "anthony",
+    "ashley",
+    "barbara",
+    "ben",
+    "benjamin",
+    "betty",
+    "brian",
+    "carlos",
+    "carol",
+    "catherine",
+    "charles",
+    "chen",
+    "chris",
+    "christopher",
+    "daniel",
+    "david",
+    "deborah",
+    "diana",
+    "donald",
+    "dorothy",
+    "edward",
+    "elizabeth",
+    "emily",
+    "emma",
+    "eric",
+    "fatima",
+    "frank",
+    "gary",
+    "george",
+    "hannah",
+    "helen",
+    "henry",
+    "hiroshi",
+    "isabella",
+    "jack",
+    "james",
+    "jane",
+    "jason",
+    "jennifer",
+    "jessica",
+    "john",
+    "jose",
+    "joseph",
+    "joshua",
+    "juan",
+    "karen",
+    "kevin",
+    "kimberly",
+    "laura",
+    "linda",
+    "lisa",
+    "luis",
+    "margaret",
+    "maria",
+    "mark",
+    "mary",
+    "matthew",
+    "michael",
+    "michelle",
+    "mohammed",
+    "nancy",
+    "olivia",
+    "patricia",
+    "paul",
+    "peter",
+    "priya",
+    "rachel",
+    "raj",
+    "rebecca",
+    "richard",
+    "robert",
+    "ryan",
+    "sandra",
+    "sarah",
+    "scott",
+    "sharon",
+    "sophia",
+    "stephen",
+    "steven",
+    "susan",
+    "thomas",
+    "timothy",
+    "wei",
+    "william",
+    "yuki",
+];
+
+/// Common surnames (lowercase)
+#[cfg(feature = "name-gazetteer")]
+const SURNAMES: &[&str] = &[
+    "adams",
+    "ahmed",
+    "allen",
+    "anderson",
+    "baker",
+    "brown",
+    "campbell",
+    "chen",
+    "clark",
+    "davis",
+    "garcia",
+    "gonzalez",
+    "green",
+    "hall",
+    "harris",
+    "hernandez",
+    "hill",
+    "jackson",
+    "johnson",
+    "jones",
+    "khan",
+    "kim",
+    "king",
+    "kumar",
+    "lee",
+    "lewis",
+    "li",
+    "lopez",
+    "martin",
+    "martinez",
+    "miller",
+    "mitchell",
+    "moore",
+    "nelson",
+    "nguyen",
+    "patel",
+    "perez",
+    "roberts",
+    "robinson",
+    "rodriguez",
+    "sanchez",
+    "scott",
+    "singh",
+    "smith",
+    "taylor",
+    "thomas",
+    "thompson",
+    "walker",
+    "wang",
+    "white",
+    "williams",
+    "wilson",
+    "wright",
+    "young",
+    "zhang",
+];
@@ -0,0 +1,106 @@
+//! Unicode normalization before detection
+//!
+//! With [`TorkConfig::normalize_unicode`](crate::TorkConfig::normalize_unicode),
+//! detectors run over a normalized copy of the input so that full-width
+//! digits or stray combining marks don't hide PII from the ASCII patterns.
+//! Matches are then mapped back to the original text, which is what gets
+//! redacted.
+
+use crate::PIIMatch;
+use std::ops::Range;
+
+/// Normalized copy of a text with the way back to the original
+pub(crate) struct Normalized {
+    text: String,
+    /// One per cluster of the original, in order: where its normalized form
+    /// starts in `text` and the bytes it came from
+    segments: Vec<(usize, Range<usize>)>,
+}
+
+impl Normalized {
+    /// Normalize `original`, or `None` if normalizing wouldn't change it
+    pub(crate) fn new(original: &str) -> Option<Self> {
+        if original.is_ascii() {
+            return None;
+        }
+        let mut text = String::with_capacity(original.len());
+        let mut segments = Vec::new();
+        let mut chars = original.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            // A character and the combining marks after it normalize together
+            let mut end = start + c.len_utf8();
+            while let Some(&(i, mark)) = chars.peek().filter(|(_, mark)| is_combining(*mark)) {
+                end = i + mark.len_utf8();
+                chars.next();
+            }
+            segments.push((text.len(), start..end));
+            normalize_cluster(&original[start..end], &mut text);
+        }
+        (text != original).then_some(Normalized { text, segments })
+    }
+
+    pub(crate) fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Original range of the cluster holding byte `pos` of the normalized text
+    fn original(&self, pos: usize) -> &Range<usize> {
+        let i = self.segments.partition_point(|(start, _)| *start <= pos);
+        &self.segments[i.saturating_sub(1)].1
+    }
+
+    /// `m`, found in the normalized text, with offsets, value and groups
+    /// moved onto `original`
+    ///
+    /// A match starting or ending inside a cluster takes the whole cluster.
+    pub(crate) fn map_back(&self, original: &str, mut m: PIIMatch) -> PIIMatch {
+        let start = self.original(m.start_index).start;
+        let end = self.original(m.end_index.max(m.start_index + 1) - 1).end;
+        for group in &mut m.groups {
+            let group_start = self.original(m.start_index + group.start).start;
+            let group_end = if group.end > group.start {
+                self.original(m.start_index + group.end - 1).end
+            } else {
+                group_start
+            };
+            group.start = group_start - start;
+            group.end = group_end - start;
+        }
+        m.start_index = start;
+        m.end_index = end;
+        m.value = original[start..end].to_string();
+        m
+    }
+}
+
+/// Whether `c` is a combining mark that attaches to the character before it
+#[cfg(feature = "unicode-casefold")]
+fn is_combining(c: char) -> bool {
+    unicode_normalization::char::canonical_combining_class(c) != 0
+}
+
+/// Append the NFKC form of `cluster` to `out`, dropping combining marks that
+/// didn't compose into a precomposed character
+#[cfg(feature = "unicode-casefold")]
+fn normalize_cluster(cluster: &str, out: &mut String) {
+    use unicode_normalization::UnicodeNormalization;
+    out.extend(cluster.nfkc().filter(|&c| !is_combining(c)));
+}
+
+/// Without normalization tables only the Combining Diacritical Marks block
+/// is recognized
+#[cfg(not(feature = "unicode-casefold"))]
+fn is_combining(c: char) -> bool {
+    ('\u{0300}'..='\u{036F}').contains(&c)
+}
+
+/// Without normalization tables, map full-width ASCII forms and the
+/// ideographic space to ASCII and drop combining marks
+#[cfg(not(feature = "unicode-casefold"))]
+fn normalize_cluster(cluster: &str, out: &mut String) {
+    out.extend(cluster.chars().filter(|&c| !is_combining(c)).map(|c| match c {
+        '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).expect("full-width form maps to ASCII"),
+        '\u{3000}' => ' ',
+        _ => c,
+    }));
+}
@@ -0,0 +1,176 @@
+//! The PII type taxonomy, shared by the `no_std` core and the full API
+
+use alloc::string::{String, ToString};
+use core::fmt;
+use core::str::FromStr;
+use serde::{Deserialize, Serialize};
+
+/// Types of PII that can be detected
+///
+/// New types are added in minor releases, so the enum is `#[non_exhaustive]`:
+/// matches outside this crate need a wildcard arm, for which
+/// [`PIIType::generic_placeholder`] is a reasonable default. Methods on the
+/// type itself ([`redaction`](PIIType::redaction), [`as_str`](PIIType::as_str),
+/// ...) always cover every variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PIIType {
+    Ssn,
+    /// US Employer Identification Number (`XX-XXXXXXX`)
+    Ein,
+    CreditCard,
+    Email,
+    Phone,
+    Address,
+    /// US ZIP code (in address context) or UK postcode
+    PostalCode,
+    IpAddress,
+    DateOfBirth,
+    Passport,
+    DriversLicense,
+    /// 17-character vehicle identification number
+    Vin,
+    BankAccount,
+    /// Bitcoin (base58 or `bc1` bech32) or Ethereum (`0x`) wallet address
+    CryptoWallet,
+    Name,
+    /// Base64 blob whose decoded text contains PII, see
+    /// [`TorkConfig::scan_encoded`](crate::TorkConfig::scan_encoded)
+    EncodedPii,
+    /// Reported by user-supplied detectors
+    Custom,
+}
+
+impl PIIType {
+    /// Get the redaction placeholder for this PII type
+    pub fn redaction(&self) -> &'static str {
+        match self {
+            PIIType::Ssn => "[SSN_REDACTED]",
+            PIIType::Ein => "[EIN_REDACTED]",
+            PIIType::CreditCard => "[CARD_REDACTED]",
+            PIIType::Email => "[EMAIL_REDACTED]",
+            PIIType::Phone => "[PHONE_REDACTED]",
+            PIIType::Address => "[ADDRESS_REDACTED]",
+            PIIType::PostalCode => "[POSTAL_REDACTED]",
+            PIIType::IpAddress => "[IP_REDACTED]",
+            PIIType::DateOfBirth => "[DOB_REDACTED]",
+            PIIType::Passport => "[PASSPORT_REDACTED]",
+            PIIType::DriversLicense => "[DL_REDACTED]",
+            PIIType::Vin => "[VIN_REDACTED]",
+            PIIType::BankAccount => "[ACCOUNT_REDACTED]",
+            PIIType::CryptoWallet => "[WALLET_REDACTED]",
+            PIIType::Name => "[NAME_REDACTED]",
+            PIIType::EncodedPii => "[ENCODED_PII_REDACTED]",
+            PIIType::Custom => "[CUSTOM_REDACTED]",
+        }
+    }
+
+    /// Placeholder that doesn't reveal the type, for callers handling
+    /// types they don't recognize
+    pub fn generic_placeholder() -> &'static str {
+        "[PII_REDACTED]"
+    }
+
+    /// Every PII type, in declaration order
+    pub fn all() -> &'static [PIIType] {
+        &[
+            PIIType::Ssn,
+            PIIType::Ein,
+            PIIType::CreditCard,
+            PIIType::Email,
+            PIIType::Phone,
+            PIIType::Address,
+            PIIType::PostalCode,
+            PIIType::IpAddress,
+            PIIType::DateOfBirth,
+            PIIType::Passport,
+            PIIType::DriversLicense,
+            PIIType::Vin,
+            PIIType::BankAccount,
+            PIIType::CryptoWallet,
+            PIIType::Name,
+            PIIType::EncodedPii,
+            PIIType::Custom,
+        ]
+    }
+
+    /// The snake_case name used in serialized form (e.g. `credit_card`)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PIIType::Ssn => "ssn",
+            PIIType::Ein => "ein",
+            PIIType::CreditCard => "credit_card",
+            PIIType::Email => "email",
+            PIIType::Phone => "phone",
+            PIIType::Address => "address",
+            PIIType::PostalCode => "postal_code",
+            PIIType::IpAddress => "ip_address",
+            PIIType::DateOfBirth => "date_of_birth",
+            PIIType::Passport => "passport",
+            PIIType::DriversLicense => "drivers_license",
+            PIIType::Vin => "vin",
+            PIIType::BankAccount => "bank_account",
+            PIIType::CryptoWallet => "crypto_wallet",
+            PIIType::Name => "name",
+            PIIType::EncodedPii => "encoded_pii",
+            PIIType::Custom => "custom",
+        }
+    }
+
+    /// Human-readable label for UIs and reports
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PIIType::Ssn => "Social Security Number",
+            PIIType::Ein => "Employer Identification Number",
+            PIIType::CreditCard => "Credit Card Number",
+            PIIType::Email => "Email Address",
+            PIIType::Phone => "Phone Number",
+            PIIType::Address => "Street Address",
+            PIIType::PostalCode => "Postal Code",
+            PIIType::IpAddress => "IP Address",
+            PIIType::DateOfBirth => "Date of Birth",
+            PIIType::Passport => "Passport Number",
+            PIIType::DriversLicense => "Driver's License Number",
+            PIIType::Vin => "Vehicle Identification Number",
+            PIIType::BankAccount => "Bank Account Number",
+            PIIType::CryptoWallet => "Crypto Wallet Address",
+            PIIType::Name => "Person Name",
+            PIIType::EncodedPii => "Encoded PII",
+            PIIType::Custom => "Custom Identifier",
+        }
+    }
+}
+
+/// Error returned when parsing an unknown PII type name
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePIITypeError(String);
+
+impl fmt::Display for ParsePIITypeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown PII type '{}'", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParsePIITypeError {}
+
+impl FromStr for PIIType {
+    type Err = ParsePIITypeError;
+
+    /// Parse the snake_case serde name (e.g. `credit_card`)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        PIIType::all()
+            .iter()
+            .find(|t| t.as_str() == s)
+            .copied()
+            .ok_or_else(|| ParsePIITypeError(s.to_string()))
+    }
+}
+
+/// Writes [`as_str`](PIIType::as_str), e.g. `credit_card`
+impl fmt::Display for PIIType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
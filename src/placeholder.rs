@@ -0,0 +1,77 @@
+//! Placeholders left by earlier redaction
+//!
+//! Governing already-redacted text must not change it again, so matches that
+//! touch a placeholder such as `[SSN_REDACTED]`, `[EMAIL_REDACTED:2]` or
+//! the marked `[SSN_REDACTED#1.0.0]` are dropped before redaction.
+
+use crate::{mark_placeholder, PIIType, TorkConfig, REDACTED_SENTENCE};
+use regex::Regex;
+use std::ops::Range;
+use std::sync::OnceLock;
+
+/// Built-in placeholders with an optional `Indexed` suffix and an optional
+/// `#version` mark, capturing the mark as `mark`
+///
+/// The index is at most four digits, too short to hold any built-in type,
+/// so wrapping a value in brackets can't hide it from detection.
+fn builtin_placeholder() -> &'static Regex {
+    static BUILTIN: OnceLock<Regex> = OnceLock::new();
+    BUILTIN.get_or_init(|| {
+        let names: Vec<String> = PIIType::all()
+            .iter()
+            .map(|t| t.redaction())
+            .chain([PIIType::generic_placeholder(), REDACTED_SENTENCE])
+            .map(|p| regex::escape(p.trim_start_matches('[').trim_end_matches(']')))
+            .collect();
+        Regex::new(&format!(r"\[(?:{})(?::\d{{1,4}})?(?:#(?P<mark>[^\]\s]+))?\]", names.join("|"))).unwrap()
+    })
+}
+
+/// Whether `text` holds a placeholder marked under
+/// [`TorkConfig::mark_redactions`](crate::TorkConfig::mark_redactions)
+///
+/// Only built-in placeholders are recognized; a bare `[SSN_REDACTED]` could
+/// be typed by anyone and doesn't count.
+pub fn was_previously_redacted(text: &str) -> bool {
+    builtin_placeholder()
+        .captures_iter(text)
+        .any(|c| c.name("mark").is_some())
+}
+
+/// Byte ranges of `text` holding a built-in placeholder, marked or not and
+/// with or without an `Indexed` suffix, or one of `config.placeholders`,
+/// plain or marked as this config would mark it
+///
+/// A marked built-in placeholder only counts when the mark is this config's
+/// `policy_version` or a plain `MAJOR.MINOR.PATCH` version, so free text
+/// after the `#` is still scanned.
+pub(crate) fn placeholder_ranges(text: &str, config: &TorkConfig) -> Vec<Range<usize>> {
+    let mut ranges: Vec<Range<usize>> = builtin_placeholder()
+        .captures_iter(text)
+        .filter(|c| {
+            c.name("mark")
+                .is_none_or(|mark| mark.as_str() == config.policy_version || is_plain_version(mark.as_str()))
+        })
+        .map(|c| c.get(0).expect("group 0 is always present").range())
+        .collect();
+    let mark = config.mark_redactions.then_some(config.policy_version.as_str());
+    for placeholder in config.placeholders.values().filter(|p| !p.is_empty()) {
+        let marked = mark_placeholder(placeholder, mark);
+        for form in [placeholder.as_str(), marked.as_str()] {
+            ranges.extend(text.match_indices(form).map(|(start, p)| start..start + p.len()));
+        }
+    }
+    ranges
+}
+
+/// Whether `mark` is a `MAJOR.MINOR.PATCH` version of at most three digits
+/// per part without leading zeros, too short to hold any built-in type
+fn is_plain_version(mark: &str) -> bool {
+    let parts: Vec<&str> = mark.split('.').collect();
+    parts.len() == 3
+        && parts.iter().all(|part| {
+            (1..=3).contains(&part.len())
+                && part.bytes().all(|b| b.is_ascii_digit())
+                && (part.len() == 1 || !part.starts_with('0'))
+        })
+}
@@ -0,0 +1,71 @@
+//! Postal-code detection
+//!
+//! UK postcodes have a distinctive shape and are matched on their own. A US
+//! ZIP code is just five digits, so [`PostalCodeDetector`] only reports one
+//! that follows a state abbreviation (`Springfield, IL 62704`) or a label
+//! (`ZIP: 62704-1234`); the context itself is not redacted.
+
+use crate::{Detector, PIIMatch, PIIType};
+use regex::Regex;
+use std::sync::OnceLock;
+
+/// Detector for [`PIIType::PostalCode`]
+#[derive(Debug, Clone)]
+pub struct PostalCodeDetector {
+    us_zip: Regex,
+    uk_postcode: Regex,
+}
+
+impl PostalCodeDetector {
+    /// Create a detector; the patterns are compiled once and shared
+    pub fn new() -> Self {
+        static SHARED: OnceLock<PostalCodeDetector> = OnceLock::new();
+        SHARED.get_or_init(Self::compile).clone()
+    }
+
+    fn compile() -> Self {
+        PostalCodeDetector {
+            us_zip: Regex::new(
+                r"(?:\b(?:A[KLRZ]|C[AOT]|D[CE]|FL|GA|HI|I[ADLN]|K[SY]|LA|M[ADEINOST]|N[CDEHJMVY]|O[HKR]|P[AR]|RI|S[CD]|T[NX]|UT|V[AT]|W[AIVY])\.?,?\s+|(?i:\b(?:zip|postal)(?:\s*code)?)\s*[:#]?\s*)(\d{5}(?:-\d{4})?)\b",
+            )
+            .unwrap(),
+            uk_postcode: Regex::new(r"\b[A-Z]{1,2}\d[A-Z\d]? ?\d[A-Z]{2}\b").unwrap(),
+        }
+    }
+
+    fn postal_match(text: &str, start: usize, end: usize) -> PIIMatch {
+        PIIMatch {
+            pii_type: PIIType::PostalCode,
+            value: text[start..end].to_string(),
+            start_index: start,
+            end_index: end,
+            location: None,
+            confidence: 1.0,
+            groups: Vec::new(),
+        }
+    }
+}
+
+impl Default for PostalCodeDetector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Detector for PostalCodeDetector {
+    fn detect(&self, text: &str) -> Vec<PIIMatch> {
+        let zips = self
+            .us_zip
+            .captures_iter(text)
+            .filter_map(|caps| caps.get(1))
+            .map(|zip| Self::postal_match(text, zip.start(), zip.end()));
+        let postcodes = self
+            .uk_postcode
+            .find_iter(text)
+            .map(|code| Self::postal_match(text, code.start(), code.end()));
+
+        let mut all: Vec<PIIMatch> = zips.chain(postcodes).collect();
+        all.sort_by_key(|m| m.start_index);
+        all
+    }
+}
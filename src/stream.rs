@@ -0,0 +1,171 @@
+//! Incremental governance of streamed text
+//!
+//! LLM responses arrive token by token, so a value such as an SSN can be
+//! split across chunks. [`StreamGovernor`] holds back the tail of what it has
+//! seen until no match could still be forming there.
+//!
+//! ```rust
+//! use tork_governance::Tork;
+//!
+//! let tork = Tork::new();
+//! let mut stream = tork.stream();
+//! let mut output = String::new();
+//! for chunk in ["My SSN is 123", "-45-", "6789, thanks"] {
+//!     output.push_str(&stream.push(chunk));
+//! }
+//! output.push_str(&stream.finish());
+//! assert_eq!(output, "My SSN is [SSN_REDACTED], thanks");
+//! ```
+
+use crate::{redact_matches, Collected, Compiled, DenyOutput, GovernanceAction, Redactor, Tork};
+use std::sync::Arc;
+
+/// Upper bound on how many bytes a [`StreamGovernor`] holds back
+///
+/// Detectors whose longest match is unknown or unbounded (e.g. street
+/// addresses) are assumed to fit in this many bytes.
+pub const MAX_STREAM_HOLDBACK: usize = 256;
+
+/// Redacts a text stream chunk by chunk, see [`Tork::stream`]
+///
+/// Uses the instance's detectors and config as they were when the stream
+/// started; a [`Tork::reload_config`] applies to the next stream. No receipt is issued and stats
+/// are not recorded; govern the assembled text if a receipt is needed.
+/// `Sentence` redaction only sees the text emitted so far, so a sentence
+/// spanning a flush is redacted in parts.
+///
+/// Each match gets its own type's action from `type_actions` or
+/// `default_action`: `Allow` leaves it in place and `Redact` or `Escalate`
+/// redacts it. Unlike [`Tork::govern`], which applies the most severe action
+/// to the whole text, an allowed match next to a redacted one stays as is.
+///
+/// A match whose action is `Deny`, a `denylist` term, or an uncertain match
+/// under a `Deny` [`uncertain_action`](crate::TorkConfig::uncertain_action)
+/// denies the stream before any of the text holding it is emitted. From then
+/// on [`deny_output`](crate::TorkConfig::deny_output) decides what follows:
+/// `Empty` emits nothing more, `Message` emits the message once,
+/// `Passthrough` emits the rest unredacted and `RedactedAnyway` keeps
+/// redacting every match. Text emitted before the denial stays emitted.
+/// `max_redaction_ratio` needs the whole text and is not applied.
+pub struct StreamGovernor<'a> {
+    tork: &'a Tork,
+    compiled: Arc<Compiled>,
+    redactor: Redactor,
+    buffer: String,
+    holdback: usize,
+    denied: bool,
+}
+
+impl<'a> StreamGovernor<'a> {
+    pub(crate) fn new(tork: &'a Tork) -> Self {
+        let compiled = tork.compiled();
+        let holdback = tork
+            .all_detectors(&compiled.detectors)
+            .map(|d| d.max_match_len().map_or(MAX_STREAM_HOLDBACK, |len| len.min(MAX_STREAM_HOLDBACK)))
+            .chain(compiled.config.denylist.iter().map(|term| term.len().min(MAX_STREAM_HOLDBACK)))
+            .max()
+            .unwrap_or(0);
+        StreamGovernor {
+            tork,
+            redactor: Redactor::new(&compiled.config),
+            compiled,
+            buffer: String::new(),
+            holdback,
+            denied: false,
+        }
+    }
+
+    /// Bytes kept back from each `push`: the longest possible match or
+    /// `denylist` term, capped at [`MAX_STREAM_HOLDBACK`]
+    pub fn holdback(&self) -> usize {
+        self.holdback
+    }
+
+    /// Whether the stream has been denied, see [`StreamGovernor`]
+    pub fn denied(&self) -> bool {
+        self.denied
+    }
+
+    /// Add a chunk and return the redacted text that is now safe to emit
+    ///
+    /// May return an empty string while the buffered text is shorter than
+    /// [`holdback`](StreamGovernor::holdback).
+    pub fn push(&mut self, chunk: &str) -> String {
+        self.buffer.push_str(chunk);
+        if self.denied && self.compiled.config.deny_output != DenyOutput::RedactedAnyway {
+            return self.deny_rest();
+        }
+        if self.buffer.len() <= self.holdback {
+            return String::new();
+        }
+
+        let collected = self.tork.collect_matches(&self.compiled, &self.buffer, &self.compiled.config);
+        let mut cut = self.buffer.len() - self.holdback;
+        while !self.buffer.is_char_boundary(cut) {
+            cut -= 1;
+        }
+        // Never split a match; pull the cut back to its start instead
+        while let Some(straddling) = collected
+            .matches
+            .iter()
+            .find(|m| m.start_index < cut && cut < m.end_index)
+        {
+            cut = straddling.start_index;
+        }
+        self.flush(cut, collected)
+    }
+
+    /// Redact and return everything still buffered, ending the stream
+    pub fn finish(&mut self) -> String {
+        if self.denied && self.compiled.config.deny_output != DenyOutput::RedactedAnyway {
+            return self.deny_rest();
+        }
+        let collected = self.tork.collect_matches(&self.compiled, &self.buffer, &self.compiled.config);
+        self.flush(self.buffer.len(), collected)
+    }
+
+    /// Govern `buffer[..cut]` with the matches `collected` from the buffer,
+    /// removing it from the buffer
+    fn flush(&mut self, cut: usize, collected: Collected) -> String {
+        let compiled = Arc::clone(&self.compiled);
+        let config = &compiled.config;
+        let mut complete: Vec<_> = collected.matches.into_iter().filter(|m| m.end_index <= cut).collect();
+        if !self.denied {
+            // The holdback covers the longest term, so one ending in the
+            // buffer can't have started in text already emitted
+            self.denied = config.denylist_hit(&self.buffer).is_some()
+                || complete
+                    .iter()
+                    .any(|m| config.action_for(&[m.pii_type]) == GovernanceAction::Deny)
+                || (config.uncertain_action == GovernanceAction::Deny
+                    && collected.uncertain.iter().any(|m| m.end_index <= cut));
+            if self.denied {
+                match &config.deny_output {
+                    DenyOutput::RedactedAnyway => {}
+                    DenyOutput::Message(message) => {
+                        self.buffer.clear();
+                        return message.clone();
+                    }
+                    _ => return self.deny_rest(),
+                }
+            } else {
+                complete.retain(|m| config.action_for(&[m.pii_type]) != GovernanceAction::Allow);
+            }
+        }
+
+        let output = redact_matches(&self.buffer[..cut], &complete, &mut self.redactor)
+            .0
+            .into_owned();
+        self.buffer.drain(..cut);
+        output
+    }
+
+    /// Output for everything buffered once the stream is denied
+    fn deny_rest(&mut self) -> String {
+        let rest = std::mem::take(&mut self.buffer);
+        match self.compiled.config.deny_output {
+            DenyOutput::Passthrough => rest,
+            _ => String::new(),
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//! Fake replacement values
+//!
+//! Under [`RedactionStyle::Synthetic`](crate::RedactionStyle::Synthetic) each
+//! match is replaced by a made-up value of the same type that the type's
+//! detector and validators would accept: cards pass Luhn for the same brand,
+//! SSNs avoid the never-issued ranges, phone numbers use the fictional
+//! `555-01XX` block, emails and IPs use documentation domains and ranges and
+//! wallet addresses carry valid checksums.
+
+use crate::{is_trivial_digit_run, vin_check_digit, wallet};
+use crate::core_detect::luhn_valid;
+use crate::{CardBrand, PIIMatch, PIIType};
+
+/// Attempts at a value differing from the original before giving up
+const MAX_ATTEMPTS: usize = 8;
+
+const FIRST_NAMES: [&str; 10] = [
+    "Alex", "Jordan", "Taylor", "Morgan", "Casey", "Riley", "Jamie", "Avery", "Quinn", "Reese",
+];
+const LAST_NAMES: [&str; 10] = [
+    "Smith", "Johnson", "Lee", "Garcia", "Brown", "Miller", "Davis", "Clark", "Lewis", "Walker",
+];
+const STREETS: [&str; 8] = ["Maple", "Oak", "Cedar", "Elm", "Pine", "Birch", "Willow", "Lake"];
+const EMAIL_DOMAINS: [&str; 3] = ["example.com", "example.net", "example.org"];
+/// IPv4 documentation ranges (RFC 5737)
+const IP_PREFIXES: [&str; 3] = ["192.0.2", "198.51.100", "203.0.113"];
+/// Characters allowed in a VIN
+const VIN_CHARS: &[u8] = b"0123456789ABCDEFGHJKLMNPRSTUVWXYZ";
+
+/// SplitMix64 generator, so a seed always yields the same values
+pub(crate) struct SyntheticRng(u64);
+
+impl SyntheticRng {
+    pub(crate) fn new(seed: u64) -> Self {
+        SyntheticRng(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `lo..=hi`
+    fn range(&mut self, lo: u32, hi: u32) -> u32 {
+        lo + (self.next_u64() % u64::from(hi - lo + 1)) as u32
+    }
+
+    fn pick<'a>(&mut self, items: &[&'a str]) -> &'a str {
+        items[self.range(0, items.len() as u32 - 1) as usize]
+    }
+
+    fn digit(&mut self) -> char {
+        char::from(b'0' + self.range(0, 9) as u8)
+    }
+}
+
+/// A fake value for `m`, or `None` if every attempt reproduced the original
+pub(crate) fn synthesize(m: &PIIMatch, rng: &mut SyntheticRng) -> Option<String> {
+    (0..MAX_ATTEMPTS)
+        .map(|_| fake_value(m, rng))
+        .find(|fake| *fake != m.value)
+}
+
+fn fake_value(m: &PIIMatch, rng: &mut SyntheticRng) -> String {
+    match m.pii_type {
+        PIIType::Ssn => {
+            // Area 000, 666 and 900-999 and group 00 are never issued
+            let area = match rng.range(1, 898) {
+                666 => 899,
+                area => area,
+            };
+            format!("{:03}-{:02}-{:04}", area, rng.range(1, 99), rng.range(1, 9999))
+        }
+        PIIType::CreditCard => card_number(&m.value, rng),
+        PIIType::Email => {
+            let local: String = (0..8).map(|_| char::from(b'a' + rng.range(0, 25) as u8)).collect();
+            format!("{}@{}", local, rng.pick(&EMAIL_DOMAINS))
+        }
+        PIIType::Phone => phone_number(m, rng),
+        PIIType::Address => {
+            if m.value.to_ascii_lowercase().contains("box") {
+                format!("PO Box {}", rng.range(1, 9999))
+            } else {
+                format!("{} {} Street", rng.range(1, 9999), rng.pick(&STREETS))
+            }
+        }
+        PIIType::IpAddress => format!("{}.{}", rng.pick(&IP_PREFIXES), rng.range(1, 254)),
+        PIIType::DateOfBirth => format!("{:02}/{:02}/{}", rng.range(1, 12), rng.range(1, 28), rng.range(1940, 2005)),
+        PIIType::Vin => vin(rng),
+        PIIType::CryptoWallet => wallet_address(&m.value, rng),
+        PIIType::BankAccount => loop {
+            let account = reshape(&m.value, rng);
+            if !is_trivial_digit_run(&account) {
+                break account;
+            }
+        },
+        PIIType::Name => {
+            let mut first = true;
+            let words: Vec<&str> = m
+                .value
+                .split(' ')
+                .map(|word| match word {
+                    // Titles such as `Dr.` are kept
+                    _ if word.ends_with('.') || word.is_empty() => word,
+                    _ if std::mem::take(&mut first) => rng.pick(&FIRST_NAMES),
+                    _ => rng.pick(&LAST_NAMES),
+                })
+                .collect();
+            words.join(" ")
+        }
+        PIIType::Ein
+        | PIIType::PostalCode
+        | PIIType::Passport
+        | PIIType::DriversLicense
+        | PIIType::EncodedPii
+        | PIIType::Custom => reshape(&m.value, rng),
+    }
+}
+
+/// `value` with each ASCII digit and letter replaced by a random one of the
+/// same kind and case, keeping everything else
+fn reshape(value: &str, rng: &mut SyntheticRng) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            '0'..='9' => rng.digit(),
+            'A'..='Z' => char::from(b'A' + rng.range(0, 25) as u8),
+            'a'..='z' => char::from(b'a' + rng.range(0, 25) as u8),
+            _ => c,
+        })
+        .collect()
+}
+
+/// A Luhn-valid number of the same brand and length, grouped like `value`
+fn card_number(value: &str, rng: &mut SyntheticRng) -> String {
+    let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+    let prefix = match CardBrand::of_digits(&digits) {
+        Some(CardBrand::Visa) => "4",
+        Some(CardBrand::Mastercard) => rng.pick(&["51", "52", "53", "54", "55"]),
+        Some(CardBrand::Amex) => "37",
+        Some(CardBrand::DinersClub) => "36",
+        Some(CardBrand::Discover) => "6011",
+        Some(CardBrand::Jcb) => "3530",
+        Some(CardBrand::UnionPay) => "62",
+        None => &digits[..1],
+    };
+    let mut fake = prefix.to_string();
+    while fake.len() < digits.len() - 1 {
+        fake.push(rng.digit());
+    }
+    let check = ('0'..='9')
+        .find(|&d| luhn_valid(&format!("{}{}", fake, d)))
+        .expect("some check digit satisfies Luhn");
+    fake.push(check);
+
+    let mut fake = fake.chars();
+    value
+        .chars()
+        .map(|c| if c.is_ascii_digit() { fake.next().expect("same digit count") } else { c })
+        .collect()
+}
+
+/// A number in the fictional `555-0100` to `555-0199` block, laid out like
+/// the original
+fn phone_number(m: &PIIMatch, rng: &mut SyntheticRng) -> String {
+    // Only ASCII alphanumerics are replaced, so group offsets still hold
+    let mut fake = reshape(&m.value, rng);
+    for group in &m.groups {
+        let digits = match group.name.as_str() {
+            "country" => "1".to_string(),
+            "area" => format!("{}{}{}", rng.range(2, 9), rng.digit(), rng.digit()),
+            "exchange" => "555".to_string(),
+            "line" => format!("01{}{}", rng.digit(), rng.digit()),
+            _ => continue,
+        };
+        fake.replace_range(group.start..group.end, &digits);
+    }
+    fake
+}
+
+/// An address of the same kind as `value`, with a random hash or key
+fn wallet_address(value: &str, rng: &mut SyntheticRng) -> String {
+    let mut bytes = |n: usize| -> Vec<u8> { (0..n).map(|_| rng.range(0, 255) as u8).collect() };
+    if value.starts_with("0x") {
+        wallet::eip55(&hex::encode(bytes(20)))
+    } else if value.starts_with('1') {
+        wallet::base58check(0x00, &bytes(20))
+    } else if value.starts_with('3') {
+        wallet::base58check(0x05, &bytes(20))
+    } else {
+        wallet::segwit_v0(&bytes(20))
+    }
+}
+
+/// A VIN with a letter, a digit and a correct check digit
+fn vin(rng: &mut SyntheticRng) -> String {
+    let mut chars: Vec<u8> = (0..17)
+        .map(|_| VIN_CHARS[rng.range(0, VIN_CHARS.len() as u32 - 1) as usize])
+        .collect();
+    chars[0] = b'1' + rng.range(0, 4) as u8;
+    chars[1] = VIN_CHARS[rng.range(10, VIN_CHARS.len() as u32 - 1) as usize];
+    let mut vin = String::from_utf8(chars).expect("VIN characters are ASCII");
+    let check = vin_check_digit(&vin).expect("VIN characters transliterate");
+    vin.replace_range(8..9, &check.to_string());
+    vin
+}
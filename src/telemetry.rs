@@ -0,0 +1,41 @@
+//! `tracing` instrumentation for governance operations
+//!
+//! Each `govern` call runs inside a `tork.govern` span carrying `pii.count`,
+//! `governance.action`, `processing_time_ns` and `receipt_id`. When PII is
+//! found an event is emitted with the detected types and count. Matched
+//! values are never recorded.
+
+use crate::{GovernanceResult, PIIType};
+use tracing::field::Empty;
+use tracing::Span;
+
+/// Open the span for a single `govern` call, with fields recorded later
+pub(crate) fn govern_span() -> Span {
+    tracing::info_span!(
+        "tork.govern",
+        pii.count = Empty,
+        governance.action = Empty,
+        processing_time_ns = Empty,
+        receipt_id = Empty,
+    )
+}
+
+/// Record the outcome of a `govern` call on its span
+pub(crate) fn record_governance(span: &Span, result: &GovernanceResult) {
+    let action = result.action.as_str();
+    span.record("pii.count", result.pii.count as u64);
+    span.record("governance.action", action);
+    span.record("processing_time_ns", result.receipt.processing_time_ns);
+    span.record("receipt_id", result.receipt.receipt_id.as_str());
+
+    if result.pii.has_pii {
+        let types: Vec<&str> = result.pii.types.iter().map(PIIType::as_str).collect();
+        tracing::info!(
+            pii.count = result.pii.count as u64,
+            pii.types = types.join(",").as_str(),
+            governance.action = action,
+            receipt_id = result.receipt.receipt_id.as_str(),
+            "PII detected"
+        );
+    }
+}
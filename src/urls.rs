@@ -0,0 +1,78 @@
+//! URL components for [`Tork::govern_url`](crate::Tork::govern_url)
+//!
+//! Only path segments and query values are handed to detection, decoded. A
+//! query entry without `=`, as in `?john@x.com`, is scanned as a value.
+//! A component is rewritten only when redaction changed it, so the rest of
+//! the URL keeps the encoding it was parsed with.
+
+use percent_encoding::{percent_decode_str, utf8_percent_encode, AsciiSet, CONTROLS};
+use url::Url;
+
+/// Characters percent-encoded in a rewritten path segment: the WHATWG
+/// path-percent-encode set plus `%` and `/`, so a redacted value can't
+/// change the path's structure
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// `url` with each decoded path segment and query value passed through
+/// `redact` along with its location, re-encoding the ones it replaces
+pub(crate) fn redact_components(mut url: Url, mut redact: impl FnMut(&str, &str) -> Option<String>) -> String {
+    let path = match url.path_segments() {
+        Some(segments) => {
+            let segments: Vec<String> = segments
+                .enumerate()
+                .map(|(i, raw)| {
+                    let decoded = percent_decode_str(raw).decode_utf8_lossy();
+                    match redact(&decoded, &format!("path/{}", i)) {
+                        Some(redacted) => utf8_percent_encode(&redacted, PATH_SEGMENT).to_string(),
+                        None => raw.to_string(),
+                    }
+                })
+                .collect();
+            Some(format!("/{}", segments.join("/")))
+        }
+        // `mailto:` and other URLs without a hierarchical path
+        None => {
+            let decoded = percent_decode_str(url.path()).decode_utf8_lossy();
+            redact(&decoded, "path").map(|redacted| utf8_percent_encode(&redacted, PATH_SEGMENT).to_string())
+        }
+    };
+    if let Some(path) = path {
+        url.set_path(&path);
+    }
+
+    if let Some(query) = url.query() {
+        let pairs: Vec<String> = query
+            .split('&')
+            .map(|pair| {
+                let Some((key, value)) = form_urlencoded::parse(pair.as_bytes()).next() else {
+                    return pair.to_string();
+                };
+                let Some((raw_key, _)) = pair.split_once('=') else {
+                    return match redact(&key, "query") {
+                        Some(redacted) => form_urlencoded::byte_serialize(redacted.as_bytes()).collect(),
+                        None => pair.to_string(),
+                    };
+                };
+                match redact(&value, &format!("query/{}", key)) {
+                    Some(redacted) => {
+                        format!("{}={}", raw_key, form_urlencoded::byte_serialize(redacted.as_bytes()).collect::<String>())
+                    }
+                    None => pair.to_string(),
+                }
+            })
+            .collect();
+        url.set_query(Some(&pairs.join("&")));
+    }
+    url.into()
+}
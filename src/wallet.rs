@@ -0,0 +1,181 @@
+//! Cryptocurrency wallet addresses
+//!
+//! [`PIIType::CryptoWallet`](crate::PIIType::CryptoWallet) matches Bitcoin
+//! base58 (`1...`, `3...`) and bech32 (`bc1...`) addresses and Ethereum
+//! `0x` addresses. Under
+//! [`TorkConfig::validate_checksums`](crate::TorkConfig::validate_checksums)
+//! each kind must carry a correct checksum: base58check for legacy Bitcoin,
+//! bech32 or bech32m for SegWit and EIP-55 for Ethereum addresses written in
+//! mixed case.
+
+use sha2::{Digest, Sha256};
+use sha3::Keccak256;
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+/// Bitcoin mainnet human-readable part
+const BECH32_HRP: &str = "bc";
+/// Checksum constants for witness version 0 (BIP-173) and later (BIP-350)
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+
+/// Whether `value` carries a correct checksum for its kind of address
+pub(crate) fn checksum_valid(value: &str) -> bool {
+    if let Some(hex) = value.strip_prefix("0x") {
+        eip55_valid(hex)
+    } else if value.len() > 3 && value[..3].eq_ignore_ascii_case("bc1") {
+        bech32_valid(value)
+    } else {
+        base58check_valid(value)
+    }
+}
+
+/// Whether a 40-digit hex address is unchecksummed (all one case) or has
+/// the EIP-55 capitalization
+fn eip55_valid(hex: &str) -> bool {
+    let has_lower = hex.bytes().any(|b| b.is_ascii_lowercase());
+    let has_upper = hex.bytes().any(|b| b.is_ascii_uppercase());
+    !(has_lower && has_upper) || eip55(&hex.to_ascii_lowercase()) == format!("0x{}", hex)
+}
+
+/// `0x` and the lowercase hex address `hex`, with letters capitalized where
+/// the matching nibble of its Keccak-256 hash is 8 or more
+pub(crate) fn eip55(hex: &str) -> String {
+    let hash = Keccak256::digest(hex.as_bytes());
+    let mut out = String::with_capacity(hex.len() + 2);
+    out.push_str("0x");
+    for (i, c) in hex.chars().enumerate() {
+        let nibble = (hash[i / 2] >> if i % 2 == 0 { 4 } else { 0 }) & 0x0f;
+        out.push(if nibble >= 8 { c.to_ascii_uppercase() } else { c });
+    }
+    out
+}
+
+/// Whether `value` decodes to a version byte, a 20-byte hash and the first
+/// four bytes of their double SHA-256
+fn base58check_valid(value: &str) -> bool {
+    let Some(bytes) = base58_decode(value) else {
+        return false;
+    };
+    if bytes.len() != 25 || !matches!(bytes[0], 0x00 | 0x05) {
+        return false;
+    }
+    let (payload, checksum) = bytes.split_at(21);
+    double_sha256(payload)[..4] == *checksum
+}
+
+/// `version` and `payload` with their base58check checksum, base58-encoded
+pub(crate) fn base58check(version: u8, payload: &[u8]) -> String {
+    let mut bytes = vec![version];
+    bytes.extend_from_slice(payload);
+    let checksum = double_sha256(&bytes);
+    bytes.extend_from_slice(&checksum[..4]);
+    base58_encode(&bytes)
+}
+
+fn double_sha256(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(bytes)).into()
+}
+
+fn base58_decode(value: &str) -> Option<Vec<u8>> {
+    // Little-endian base-256 digits of the number so far
+    let mut number: Vec<u8> = Vec::new();
+    for c in value.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in &mut number {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            number.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    // Each leading `1` stands for a zero byte
+    let zeros = value.bytes().take_while(|&c| c == b'1').count();
+    Some(std::iter::repeat_n(0, zeros).chain(number.into_iter().rev()).collect())
+}
+
+fn base58_encode(bytes: &[u8]) -> String {
+    // Little-endian base-58 digits of the number so far
+    let mut digits: Vec<u8> = Vec::new();
+    for &byte in bytes {
+        let mut carry = u32::from(byte);
+        for digit in &mut digits {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+    let zeros = bytes.iter().take_while(|&&b| b == 0).count();
+    std::iter::repeat_n(b'1', zeros)
+        .chain(digits.into_iter().rev().map(|d| BASE58_ALPHABET[usize::from(d)]))
+        .map(char::from)
+        .collect()
+}
+
+/// Whether a `bc1` address in a single case has the bech32 checksum for
+/// witness version 0 or the bech32m one for later versions
+fn bech32_valid(value: &str) -> bool {
+    if value.bytes().any(|b| b.is_ascii_lowercase()) && value.bytes().any(|b| b.is_ascii_uppercase()) {
+        return false;
+    }
+    let lower = value.to_ascii_lowercase();
+    let data: Option<Vec<u8>> = lower[BECH32_HRP.len() + 1..]
+        .bytes()
+        .map(|c| BECH32_CHARSET.iter().position(|&a| a == c).map(|i| i as u8))
+        .collect();
+    let Some(data) = data else {
+        return false;
+    };
+    let expected = match data.first() {
+        Some(0) => BECH32_CONST,
+        Some(1..=16) => BECH32M_CONST,
+        _ => return false,
+    };
+    data.len() > 6 && bech32_polymod(&data) == expected
+}
+
+/// A `bc1q` address for the witness version 0 program `program`
+pub(crate) fn segwit_v0(program: &[u8]) -> String {
+    let mut data = vec![0u8];
+    let (mut acc, mut bits) = (0u32, 0);
+    for &byte in program {
+        acc = (acc << 8) | u32::from(byte);
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            data.push(((acc >> bits) & 31) as u8);
+        }
+    }
+    if bits > 0 {
+        data.push(((acc << (5 - bits)) & 31) as u8);
+    }
+    let mut padded = data.clone();
+    padded.extend([0; 6]);
+    let polymod = bech32_polymod(&padded) ^ BECH32_CONST;
+    data.extend((0..6).map(|i| ((polymod >> (5 * (5 - i))) & 31) as u8));
+
+    let mut out = format!("{}1", BECH32_HRP);
+    out.extend(data.iter().map(|&d| char::from(BECH32_CHARSET[usize::from(d)])));
+    out
+}
+
+/// BIP-173 checksum state over the expanded `bc` prefix followed by `data`
+fn bech32_polymod(data: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [0x3b6a_57b2, 0x2650_8e6d, 0x1ea1_19fa, 0x3d42_33dd, 0x2a14_62b3];
+    let hrp = BECH32_HRP.bytes();
+    let expanded = hrp.clone().map(|c| c >> 5).chain([0]).chain(hrp.map(|c| c & 31));
+    expanded.chain(data.iter().copied()).fold(1, |chk, value| {
+        let top = chk >> 25;
+        let chk = ((chk & 0x01ff_ffff) << 5) ^ u32::from(value);
+        (0..5)
+            .filter(|i| (top >> i) & 1 == 1)
+            .fold(chk, |chk, i| chk ^ GENERATOR[i])
+    })
+}
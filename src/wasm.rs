@@ -0,0 +1,31 @@
+//! Browser bindings (`wasm` feature)
+//!
+//! Build with `wasm-pack build --features wasm` and call from JavaScript:
+//!
+//! ```js
+//! import { wasm_govern } from "tork-governance";
+//!
+//! const result = wasm_govern("My SSN is 123-45-6789");
+//! console.log(result.output); // "My SSN is [SSN_REDACTED]"
+//! ```
+
+use crate::Tork;
+use wasm_bindgen::prelude::*;
+
+thread_local! {
+    // Browsers run the module on a single thread, so one instance per thread
+    // keeps compiled patterns and stats across calls.
+    static TORK: Tork = Tork::new();
+}
+
+/// Govern `input` with a default-configured instance
+///
+/// Returns the [`GovernanceResult`](crate::GovernanceResult) as a plain
+/// JavaScript object with the same field names as its serde form.
+#[wasm_bindgen]
+pub fn wasm_govern(input: &str) -> JsValue {
+    TORK.with(|tork| {
+        let result = tork.govern(input);
+        serde_wasm_bindgen::to_value(&result).expect("governance result serializes")
+    })
+}
@@ -0,0 +1,71 @@
+//! Tests for the `tork` binary (`cli` feature)
+
+#![cfg(feature = "cli")]
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+fn tork() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_tork"))
+}
+
+fn fixture(name: &str) -> String {
+    format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name)
+}
+
+#[test]
+fn test_scan_file_prints_redacted_text() {
+    let output = tork().args(["scan", &fixture("pii.txt")]).output().unwrap();
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "Customer [EMAIL_REDACTED] reported SSN [SSN_REDACTED].\n"
+    );
+}
+
+#[test]
+fn test_scan_deny_exit_code() {
+    let flagged = tork()
+        .args(["scan", &fixture("pii.txt"), "--deny-exit-code", "3"])
+        .output()
+        .unwrap();
+    assert_eq!(flagged.status.code(), Some(3));
+
+    let clean = tork()
+        .args(["scan", &fixture("clean.txt"), "--deny-exit-code", "3"])
+        .output()
+        .unwrap();
+    assert_eq!(clean.status.code(), Some(0));
+}
+
+#[test]
+fn test_scan_stdin_json_report() {
+    let mut child = tork()
+        .args(["scan", "-", "--format", "json"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"SSN 123-45-6789")
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    assert!(output.status.success());
+
+    let report: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(report["action"], "redact");
+    assert_eq!(report["pii"]["matches"][0]["pii_type"], "ssn");
+    assert!(report["receipt"]["receipt_id"]
+        .as_str()
+        .unwrap()
+        .starts_with("rcpt_"));
+}
+
+#[test]
+fn test_scan_missing_file() {
+    let output = tork().args(["scan", "does-not-exist.txt"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+}
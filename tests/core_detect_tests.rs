@@ -0,0 +1,95 @@
+//! Tests for `core_detect`, which also run with `--no-default-features`
+
+use tork_governance::core_detect::{CoreDetector, CoreMatch};
+use tork_governance::PIIType;
+
+#[test]
+fn test_core_detects_and_redacts_builtins() {
+    let detector = CoreDetector::new();
+    let text = "SSN 123-45-6789, card 4111 1111 1111 1111, mail jane@example.com, born 1990-03-15";
+    let result = detector.detect_pii(text);
+
+    assert!(result.has_pii);
+    assert_eq!(result.count, 4);
+    let types: Vec<PIIType> = result.matches.iter().map(|m| m.pii_type).collect();
+    assert_eq!(
+        types,
+        [PIIType::Ssn, PIIType::CreditCard, PIIType::Email, PIIType::DateOfBirth]
+    );
+    assert_eq!(result.matches[0].value(text), "123-45-6789");
+    assert_eq!(
+        result.redacted_text,
+        "SSN [SSN_REDACTED], card [CARD_REDACTED], mail [EMAIL_REDACTED], born [DOB_REDACTED]"
+    );
+}
+
+#[test]
+fn test_core_applies_validators_and_overlap_rules() {
+    let detector = CoreDetector::new();
+
+    // Luhn fails, so it is not a card
+    let matches = detector.detect("4111 1111 1111 1112");
+    assert!(matches.iter().all(|m| m.pii_type != PIIType::CreditCard));
+
+    // A digit group of a hyphenated ID is not an account number
+    assert!(!detector.has_pii("order 2024-12345678-A"));
+
+    // Not a calendar date
+    assert!(!detector.has_pii("born 1990-02-30"));
+
+    // Without today's date, only a birth keyword marks a birth date
+    assert!(!detector.has_pii("released 1990-03-15"));
+    let dated = CoreDetector::new().with_today(2026, 10, 16);
+    assert!(dated.has_pii("released 1990-03-15"));
+    assert!(!dated.has_pii("released 2024-03-15"));
+
+    // Hex tokens such as dashless UUIDs aren't Bitcoin addresses
+    assert!(detector
+        .detect("id 3fa85f6457174562b3fc2c963f66afa6")
+        .iter()
+        .all(|m| m.pii_type != PIIType::CryptoWallet));
+
+    let matches = detector.detect("account 987654321");
+    assert_eq!(
+        matches,
+        [CoreMatch {
+            pii_type: PIIType::BankAccount,
+            start: 8,
+            end: 17,
+            confidence: 1.0,
+        }]
+    );
+}
+
+#[test]
+fn test_core_clean_text_is_unchanged() {
+    let result = CoreDetector::default().detect_pii("nothing to see here");
+    assert!(!result.has_pii);
+    assert!(result.matches.is_empty());
+    assert_eq!(result.redacted_text, "nothing to see here");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_core_agrees_with_detect_pii() {
+    let detector = CoreDetector::new();
+    for text in [
+        "SSN: 123-45-6789 and EIN 12-3456789",
+        "Call 555-123-4567 or (555) 987-6543",
+        "Card 378282246310005, account 98765432101",
+        "Lives at 123 Main Street, Apt 4B, server 192.168.1.1",
+        "Born January 15, 1990; passport AB1234567; VIN 1HGCM82633A004352",
+        "Wallet 0x52908400098527886E0F7030069857D2E4169EE7",
+    ] {
+        let std_result = tork_governance::detect_pii(text);
+        let core_result = detector.detect_pii(text);
+        let std_matches: Vec<_> = std_result
+            .matches
+            .iter()
+            .map(|m| (m.pii_type, m.start_index, m.end_index))
+            .collect();
+        let core_matches: Vec<_> = core_result.matches.iter().map(|m| (m.pii_type, m.start, m.end)).collect();
+        assert_eq!(core_matches, std_matches, "{}", text);
+        assert_eq!(core_result.redacted_text, std_result.redacted_text, "{}", text);
+    }
+}
@@ -0,0 +1,91 @@
+//! Tests for the `metrics` feature
+#![cfg(all(feature = "metrics", not(target_arch = "wasm32")))]
+
+use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+use metrics_util::MetricKind;
+use tork_governance::{describe_metrics, GovernanceAction, Tork, TorkConfig};
+
+/// A recorded metric as `(kind, name, labels, value)`
+type Metric = (MetricKind, String, Vec<(String, String)>, DebugValue);
+
+/// Run `f` under a local debugging recorder, returning every metric it emitted
+fn capture<F: FnOnce()>(f: F) -> Vec<Metric> {
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+    metrics::with_local_recorder(&recorder, f);
+    snapshotter
+        .snapshot()
+        .into_vec()
+        .into_iter()
+        .map(|(key, _, _, value)| {
+            let (kind, key) = key.into_parts();
+            let labels = key
+                .labels()
+                .map(|label| (label.key().to_string(), label.value().to_string()))
+                .collect();
+            (kind, key.name().to_string(), labels, value)
+        })
+        .collect()
+}
+
+fn counter(metrics: &[Metric], name: &str, labels: &[(&str, &str)]) -> u64 {
+    metrics
+        .iter()
+        .find_map(|(kind, n, l, value)| match value {
+            DebugValue::Counter(count)
+                if *kind == MetricKind::Counter
+                    && n == name
+                    && l.iter().map(|(k, v)| (k.as_str(), v.as_str())).eq(labels.iter().copied()) =>
+            {
+                Some(*count)
+            }
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_counters_increment_per_govern_call() {
+    let config = TorkConfig {
+        default_action: GovernanceAction::Deny,
+        ..TorkConfig::default()
+    };
+    let metrics = capture(|| {
+        describe_metrics();
+        let tork = Tork::new();
+        tork.govern("hello");
+        tork.govern("SSN: 123-45-6789");
+        tork.govern("email jane@example.com");
+        tork.govern_with("SSN: 123-45-6789", &config);
+    });
+
+    assert_eq!(counter(&metrics, "tork_govern_total", &[]), 4);
+    assert_eq!(counter(&metrics, "tork_pii_detected_total", &[]), 3);
+    assert_eq!(counter(&metrics, "tork_action_total", &[("action", "allow")]), 1);
+    assert_eq!(counter(&metrics, "tork_action_total", &[("action", "redact")]), 2);
+    assert_eq!(counter(&metrics, "tork_action_total", &[("action", "deny")]), 1);
+
+    let latencies = metrics.iter().find_map(|(kind, name, _, value)| match value {
+        DebugValue::Histogram(values) if *kind == MetricKind::Histogram && name == "tork_processing_seconds" => {
+            Some(values.len())
+        }
+        _ => None,
+    });
+    assert_eq!(latencies, Some(4));
+}
+
+#[test]
+fn test_metrics_never_carry_pii_values() {
+    let metrics = capture(|| {
+        Tork::new().govern("SSN: 123-45-6789, email: jane@example.com");
+    });
+
+    assert!(!metrics.is_empty());
+    for (_, name, labels, _) in &metrics {
+        assert!(name.starts_with("tork_"), "{}", name);
+        for (key, value) in labels {
+            assert_eq!(key, "action");
+            assert!(!value.contains("123-45-6789") && !value.contains("jane@example.com"));
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Tests for the `tracing` feature
+#![cfg(feature = "tracing")]
+
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+use tork_governance::Tork;
+
+#[derive(Clone, Default)]
+struct CaptureWriter(Arc<Mutex<Vec<u8>>>);
+
+impl Write for CaptureWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.0.lock().unwrap().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+fn capture<F: FnOnce()>(f: F) -> String {
+    let writer = CaptureWriter::default();
+    let sink = writer.clone();
+    let subscriber = tracing_subscriber::fmt()
+        .with_writer(move || sink.clone())
+        .with_ansi(false)
+        .with_span_events(tracing_subscriber::fmt::format::FmtSpan::CLOSE)
+        .finish();
+    tracing::subscriber::with_default(subscriber, f);
+    let bytes = writer.0.lock().unwrap().clone();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[test]
+fn test_pii_event_has_action_and_count_but_no_value() {
+    let output = capture(|| {
+        Tork::new().govern("SSN: 123-45-6789, email: jane@example.com");
+    });
+
+    assert!(output.contains("PII detected"));
+    assert!(output.contains("governance.action=\"redact\""));
+    assert!(output.contains("pii.count=2"));
+    assert!(output.contains("ssn"));
+    assert!(!output.contains("123-45-6789"));
+    assert!(!output.contains("jane@example.com"));
+}
+
+#[test]
+fn test_span_records_receipt_and_timing() {
+    let mut receipt_id = String::new();
+    let output = capture(|| {
+        receipt_id = Tork::new().govern("clean text").receipt.receipt_id;
+    });
+
+    assert!(output.contains("tork.govern"));
+    assert!(output.contains(&receipt_id));
+    assert!(output.contains("processing_time_ns="));
+    assert!(!output.contains("PII detected"));
+}
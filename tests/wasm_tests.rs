@@ -0,0 +1,21 @@
+//! Browser tests for the `wasm` feature
+//!
+//! Run with `wasm-pack test --headless --firefox --features wasm`.
+
+#![cfg(all(feature = "wasm", target_arch = "wasm32"))]
+
+use tork_governance::wasm::wasm_govern;
+use tork_governance::{GovernanceAction, GovernanceResult, PIIType};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen_test]
+fn test_wasm_govern_detects_ssn() {
+    let value = wasm_govern("My SSN is 123-45-6789");
+    let result: GovernanceResult = serde_wasm_bindgen::from_value(value).unwrap();
+
+    assert_eq!(result.action, GovernanceAction::Redact);
+    assert!(result.pii.types.contains(&PIIType::Ssn));
+    assert_eq!(result.output, "My SSN is [SSN_REDACTED]");
+}